@@ -1,6 +1,8 @@
 //! 类型转换类契定。
 //!
 
+use crate::num::AlignUpwards;
+
 /// 定义将只读引用转化至常量指针的契定。
 pub trait AsPtr<T>: AsRef<T> {
     /// 将当前只读引用转化至常量指针。
@@ -25,6 +27,74 @@ pub trait AsPtrMut<T>: AsPtr<T> {
     }
 }
 
+/// 定义在转化为指针时检查空指针的契定。
+///
+/// 适用于重写了 [AsPtr::as_ptr] 以返回内部句柄指针、且该指针可能为空的包装类型。
+///
+/// [AsPtr::as_ptr]: trait.AsPtr.html#method.as_ptr
+pub trait TryAsPtr<T>: AsPtr<T> {
+    /// 将当前只读引用转化至常量指针，指针为空时返回 `None`。
+    fn try_as_ptr(&self) -> Option<*const T> {
+        let ptr = unsafe { AsPtr::<T>::as_ptr(self) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(ptr)
+        }
+    }
+
+    /// 将当前只读引用转化至可写指针，指针为空时返回 `None`。
+    fn try_as_ptr_mut(&self) -> Option<*mut T>
+    where
+        Self: AsPtrMut<T>,
+    {
+        let ptr = unsafe { AsPtrMut::<T>::as_ptr_mut(self) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(ptr)
+        }
+    }
+}
+
+/// 定义将只读引用视作字节数组的契定。
+///
+/// # Safety
+///
+/// 实现者必须保证类型为 `#[repr(C)]` 等布局确定的类型，且不含任何填充字节，
+/// 其全部字节均已初始化；读取填充字节属于未定义行为。
+pub unsafe trait AsBytes: Sized {
+    /// 将当前值的内存表示视作长度为 `size_of::<Self>()` 的字节数组。
+    fn as_bytes(&self) -> &[u8] {
+        unsafe {
+            core::slice::from_raw_parts(
+                self as *const Self as *const u8,
+                core::mem::size_of::<Self>(),
+            )
+        }
+    }
+}
+
+/// 定义将可变引用视作可写字节数组的契定。
+///
+/// # Safety
+///
+/// 除 [AsBytes] 的要求外，实现者必须保证类型的任意比特组合都是合法值
+/// （如仅由整数组成的结构），`bool`、`char`、枚举及引用等类型不满足此要求。
+///
+/// [AsBytes]: trait.AsBytes.html
+pub unsafe trait AsBytesMut: AsBytes {
+    /// 将当前值的内存表示视作长度为 `size_of::<Self>()` 的可写字节数组。
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                self as *mut Self as *mut u8,
+                core::mem::size_of::<Self>(),
+            )
+        }
+    }
+}
+
 /// 用于帮助实现 [AsRef] 契定的宏。
 ///
 /// [AsRef]: https://doc.rust-lang.org/std/convert/trait.AsRef.html
@@ -105,7 +175,7 @@ macro_rules! impl_as_mut {
     ($Type:ty, $Target:ty) => {
         impl AsMut<$Target> for $Type {
             fn as_mut(&mut self) -> &mut $Target {
-                unsafe { std::mem::transmute::<&mut $Type, &mut $Target>(self) }
+                unsafe { ::core::mem::transmute::<&mut $Type, &mut $Target>(self) }
             }
         }
     };
@@ -198,6 +268,98 @@ macro_rules! impl_as_ptr_mut {
     };
 }
 
+/// 用于帮助实现 [TryAsPtr] 契定的宏。
+///
+/// [TryAsPtr]: trait.TryAsPtr.html
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{impl_try_as_ptr, AsPtr, AsPtrMut, TryAsPtr};
+///
+/// struct Bar {}
+/// struct Handle {
+///     raw: *mut Bar,
+/// }
+///
+/// impl AsRef<Bar> for Handle {
+///     fn as_ref(&self) -> &Bar {
+///         unsafe { &*self.raw }
+///     }
+/// }
+///
+/// impl AsPtr<Bar> for Handle {
+///     unsafe fn as_ptr(&self) -> *const Bar {
+///         self.raw
+///     }
+/// }
+///
+/// impl AsPtrMut<Bar> for Handle {
+///     unsafe fn as_ptr_mut(&self) -> *mut Bar {
+///         self.raw
+///     }
+/// }
+///
+/// impl_try_as_ptr!(Handle, Bar);
+///
+/// let h = Handle { raw: std::ptr::null_mut() };
+/// assert!(h.try_as_ptr().is_none());
+/// assert!(h.try_as_ptr_mut().is_none());
+/// ```
+#[macro_export]
+macro_rules! impl_try_as_ptr {
+    ($Type:ty) => {
+        impl TryAsPtr<$Type> for $Type {}
+    };
+
+    ($Type:ty, $Target:ty) => {
+        impl TryAsPtr<$Target> for $Type {}
+    };
+
+    ($Type:ty, $Target:ty, $Expr:tt) => {
+        impl_try_as_ptr!($Type, $Target);
+    };
+}
+
+/// 用于帮助实现 [AsBytes] 及 [AsBytesMut] 契定的宏。
+///
+/// 宏展开为 `unsafe impl`，调用者需保证类型满足 [AsBytes] 及 [AsBytesMut] 的
+/// `# Safety` 要求，宏本身无法检查这一点。
+///
+/// [AsBytes]: trait.AsBytes.html
+/// [AsBytesMut]: trait.AsBytesMut.html
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{impl_as_bytes, AsBytes, AsBytesMut};
+///
+/// #[repr(C)]
+/// struct Foo {
+///     a: u16,
+///     b: u16,
+/// }
+///
+/// impl_as_bytes!(Foo);
+///
+/// let mut f = Foo { a: 1, b: 2 };
+/// assert_eq!(f.as_bytes().len(), 4);
+/// f.as_bytes_mut()[2..].copy_from_slice(&7u16.to_ne_bytes());
+/// assert_eq!(f.b, 7);
+/// ```
+#[macro_export]
+macro_rules! impl_as_bytes {
+    ($($Type:ty),* $(,)?) => {
+        $(
+            unsafe impl AsBytes for $Type {}
+            unsafe impl AsBytesMut for $Type {}
+        )*
+    };
+}
+
+// 基本数值类型不含填充字节，且任意比特组合均为合法值。
+impl_as_bytes!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
 /// 用于帮助在单个类型上实现 `AsRef + AsMut + AsPtr + AsPtrMut` 等契定的宏。
 #[macro_export(local_inner_macros)]
 macro_rules! impl_as_bundle {
@@ -241,6 +403,62 @@ where
 {
 }
 
+/// 计算两个指针之间相差的元素个数，即 `a - b`。
+///
+/// 零大小类型始终返回 `0`。
+///
+/// # Safety
+///
+/// `a` 与 `b` 必须指向同一块内存分配（或其末尾之后一个元素）内的元素。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{ptr_elem_distance};
+///
+/// let a = [1u32, 2, 3, 4];
+/// assert_eq!(unsafe { ptr_elem_distance(&a[3], &a[0]) }, 3);
+/// assert_eq!(unsafe { ptr_elem_distance(&a[0], &a[3]) }, -3);
+/// ```
+pub unsafe fn ptr_elem_distance<T>(a: *const T, b: *const T) -> isize {
+    let size = core::mem::size_of::<T>() as isize;
+    if size == 0 {
+        return 0;
+    }
+    (a as isize - b as isize) / size
+}
+
+/// 将指针地址向上对齐到 `align`，返回对齐后的指针。
+///
+/// 结果通过在原指针上偏移得到，因此保留原指针的来源（provenance）。
+///
+/// # Safety
+///
+/// 对齐后的地址必须仍位于 `ptr` 所属的同一内存分配之内（或其末尾），
+/// 否则返回的指针无效，解引用将导致未定义行为。
+///
+/// # Panics
+///
+/// `align` 不是 2 的幂时触发异常。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{align_ptr_up};
+///
+/// let mut arena = [0u8; 64];
+/// let p = unsafe { align_ptr_up(arena.as_mut_ptr().add(1), 16) };
+/// assert_eq!(p as usize % 16, 0);
+/// ```
+pub unsafe fn align_ptr_up(ptr: *mut u8, align: usize) -> *mut u8 {
+    assert!(
+        align.is_power_of_two(),
+        "align_ptr_up: align must be a power of two"
+    );
+    let addr = ptr as usize;
+    ptr.add(addr.align_upwards(align) - addr)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,7 +503,7 @@ mod tests {
         unsafe {
             let f = Foo { v: 123 };
             assert_eq!(f.as_ptr(), &f as *const Foo);
-            let v = std::ptr::read(f.as_ptr() as *const usize);
+            let v = std::ptr::read(AsPtr::<usize>::as_ptr(&f));
             assert_eq!(v, f.v);
             let v2 = read_ptr(&f);
             assert_eq!(v, v2);
@@ -308,4 +526,116 @@ mod tests {
             write_ptr_mut(f);
         }
     }
+
+    #[test]
+    fn test_ptr_elem_distance() {
+        unsafe {
+            let a = [Foo { v: 1 }, Foo { v: 2 }, Foo { v: 3 }, Foo { v: 4 }];
+            let base = a.as_ptr();
+            assert_eq!(ptr_elem_distance(&a[3], base), 3);
+            assert_eq!(ptr_elem_distance(base, &a[3]), -3);
+            assert_eq!(ptr_elem_distance(base, base), 0);
+            assert_eq!(ptr_elem_distance(base.add(a.len()), base), 4);
+            let z = [(), (), ()];
+            assert_eq!(ptr_elem_distance(&z[2], &z[0]), 0);
+        }
+    }
+
+    #[test]
+    fn test_align_ptr_up() {
+        let mut arena = [0u8; 64];
+        let base = arena.as_mut_ptr();
+        let odd = (0..16)
+            .map(|i| unsafe { base.add(i) })
+            .find(|&p| p as usize % 2 == 1)
+            .unwrap();
+        unsafe {
+            let p = align_ptr_up(odd, 16);
+            assert_eq!(p as usize % 16, 0);
+            assert!(p > odd && p < odd.add(16));
+            assert!(p < base.add(arena.len()));
+            assert_eq!(align_ptr_up(p, 16), p);
+            assert_eq!(align_ptr_up(odd, 1), odd);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "power of two")]
+    fn test_align_ptr_up_invalid() {
+        let mut arena = [0u8; 8];
+        unsafe {
+            align_ptr_up(arena.as_mut_ptr(), 12);
+        }
+    }
+
+    #[repr(C)]
+    struct Pixel {
+        r: u8,
+        g: u8,
+        b: u8,
+        a: u8,
+    }
+
+    impl_as_bytes!(Foo, Pixel);
+
+    #[test]
+    fn test_as_bytes() {
+        let mut f = Foo { v: 0x1234 };
+        assert_eq!(f.as_bytes(), &0x1234usize.to_ne_bytes());
+        assert_eq!(f.as_bytes().as_ptr(), &f as *const Foo as *const u8);
+        f.as_bytes_mut().copy_from_slice(&42usize.to_ne_bytes());
+        assert_eq!(f.v, 42);
+        let mut p = Pixel {
+            r: 1,
+            g: 2,
+            b: 3,
+            a: 4,
+        };
+        assert_eq!(p.as_bytes(), [1, 2, 3, 4]);
+        p.as_bytes_mut()[3] = 255;
+        assert_eq!((p.r, p.g, p.b, p.a), (1, 2, 3, 255));
+    }
+
+    struct Handle {
+        raw: *mut Foo,
+    }
+
+    impl AsRef<Foo> for Handle {
+        fn as_ref(&self) -> &Foo {
+            unsafe { &*self.raw }
+        }
+    }
+
+    impl AsPtr<Foo> for Handle {
+        unsafe fn as_ptr(&self) -> *const Foo {
+            self.raw
+        }
+    }
+
+    impl AsPtrMut<Foo> for Handle {
+        unsafe fn as_ptr_mut(&self) -> *mut Foo {
+            self.raw
+        }
+    }
+
+    impl_try_as_ptr!(Foo);
+    impl_try_as_ptr!(Handle, Foo);
+
+    #[test]
+    fn test_try_as_ptr() {
+        let mut f = Foo { v: 7 };
+        assert_eq!(f.try_as_ptr(), Some(&f as *const Foo));
+        assert_eq!(f.try_as_ptr_mut(), Some(&f as *const Foo as *mut Foo));
+        let null = Handle {
+            raw: std::ptr::null_mut(),
+        };
+        assert_eq!(null.try_as_ptr(), None);
+        assert_eq!(null.try_as_ptr_mut(), None);
+        let h = Handle { raw: &mut f };
+        if let Some(p) = h.try_as_ptr_mut() {
+            unsafe { (*p).v = 9 };
+        }
+        assert_eq!(h.try_as_ptr(), Some(&f as *const Foo));
+        assert_eq!(f.v, 9);
+    }
 }
@@ -0,0 +1,83 @@
+//! 不依赖 `std` 的浮点取整运算。
+//!
+
+/// 为 `no_std` 环境补充 `core` 中缺失的浮点取整方法。
+///
+/// 启用 `std` 特性时不会编译此模块，同名的固有方法优先生效。
+pub(crate) trait FloatExt {
+    /// 返回绝对值。
+    fn abs(self) -> Self;
+
+    /// 返回向零取整的值。
+    fn trunc(self) -> Self;
+
+    /// 返回四舍五入（远离零）的值。
+    fn round(self) -> Self;
+}
+
+macro_rules! impl_float_ext {
+    ($Type:ty, $Int:ty, $Bits:ty) => {
+        impl FloatExt for $Type {
+            fn abs(self) -> Self {
+                <$Type>::from_bits(self.to_bits() & !(1 << (<$Bits>::BITS - 1)))
+            }
+
+            fn trunc(self) -> Self {
+                // 超过尾数精度的值（以及 NaN 与无穷大）本身即为整数。
+                if FloatExt::abs(self) < (1u64 << (<$Type>::MANTISSA_DIGITS - 1)) as $Type {
+                    self as $Int as $Type
+                } else {
+                    self
+                }
+            }
+
+            fn round(self) -> Self {
+                let t = FloatExt::trunc(self);
+                if self - t >= 0.5 {
+                    t + 1.0
+                } else if self - t <= -0.5 {
+                    t - 1.0
+                } else {
+                    t
+                }
+            }
+        }
+    };
+}
+
+impl_float_ext!(f32, i32, u32);
+impl_float_ext!(f64, i64, u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_float_ext() {
+        let values = [
+            0.0f64,
+            -0.0,
+            0.4,
+            0.5,
+            0.6,
+            -0.4,
+            -0.5,
+            -0.6,
+            1.5,
+            -2.5,
+            1e20,
+            -1e20,
+            4503599627370495.5,
+        ];
+        for &v in &values {
+            assert_eq!(FloatExt::abs(v), v.abs());
+            assert_eq!(FloatExt::trunc(v), v.trunc());
+            assert_eq!(FloatExt::round(v), v.round());
+            let v = v as f32;
+            assert_eq!(FloatExt::abs(v), v.abs());
+            assert_eq!(FloatExt::trunc(v), v.trunc());
+            assert_eq!(FloatExt::round(v), v.round());
+        }
+        assert!(FloatExt::round(f64::NAN).is_nan());
+    }
+}
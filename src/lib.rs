@@ -1,5 +1,18 @@
 //! 用于 `Pavo` 框架的通用契定库。
 //!
+//! 默认启用 `std` 特性；关闭默认特性后可在 `no_std` 环境中使用，
+//! 依赖堆分配的部分由 `alloc` 特性控制。
+
+// 单元测试使用 `std`（如 `vec!`、`String`、`Arc` 及 `HashSet`），并需要以 `std` 的浮点
+// 方法校验 `float` 模块，因此测试构建总是链接 `std`；`no_std` 配置由 CI 中
+// `cargo build --no-default-features` 及 `--features alloc` 的构建步骤覆盖。
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(any(not(feature = "std"), test))]
+mod float;
 
 pub mod convert;
 #[doc(inline)]
@@ -17,5 +30,12 @@ pub mod wrap;
 #[doc(inline)]
 pub use wrap::*;
 
+#[doc(hidden)]
+#[cfg(feature = "alloc")]
+pub mod __private {
+    pub use alloc::boxed::Box;
+    pub use alloc::vec::Vec;
+}
+
 #[cfg(test)]
 mod tests {}
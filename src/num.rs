@@ -1,10 +1,22 @@
 //! 数值类契定。
 //！
 
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float::FloatExt;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+pub mod const_align;
+
 /// 定义将数值向下对齐到指定倍数的契定。
 pub trait AlignDownwards {
     /// 将数值向下对齐到指定倍数。
     ///
+    /// `align` 为 `0` 时会 panic；对齐值来自运行时配置时请使用 [try_align_downwards]。
+    ///
+    /// [try_align_downwards]: #tymethod.try_align_downwards
+    ///
     /// # Examples
     ///
     /// ```
@@ -13,12 +25,46 @@ pub trait AlignDownwards {
     /// assert_eq!(65.align_downwards(64), 64);
     /// ```
     fn align_downwards(self, align: Self) -> Self;
+
+    /// 将数值向下对齐到指定倍数，`align` 为 `0` 时返回 `None`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pavo_traits::{AlignDownwards};
+    /// assert_eq!(65.try_align_downwards(64), Some(64));
+    /// assert_eq!(65.try_align_downwards(0), None);
+    /// ```
+    fn try_align_downwards(self, align: Self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// 将数值向下对齐到指定的 2 的幂倍数，使用位掩码代替取余运算。
+    ///
+    /// `align` 必须为 2 的幂，调试模式下会进行断言。负数按补码向负无穷方向对齐，
+    /// 这一点与向零方向对齐的 [align_downwards] 不同。
+    ///
+    /// [align_downwards]: #tymethod.align_downwards
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pavo_traits::{AlignDownwards};
+    /// assert_eq!(65u32.align_downwards_pow2(64), 64);
+    /// assert_eq!((-1i32).align_downwards_pow2(16), -16);
+    /// ```
+    fn align_downwards_pow2(self, align: Self) -> Self;
 }
 
 /// 定义将数值向上对齐到指定倍数的契定。
 pub trait AlignUpwards {
     /// 将数值向上对齐到指定倍数。
     ///
+    /// 对齐结果超出类型范围时会溢出（调试模式下 panic，发布模式下回绕），
+    /// `align` 为 `0` 时会 panic；需要检查时请使用 [checked_align_upwards]。
+    ///
+    /// [checked_align_upwards]: #tymethod.checked_align_upwards
+    ///
     /// # Examples
     ///
     /// ```
@@ -27,6 +73,70 @@ pub trait AlignUpwards {
     /// assert_eq!(65.align_upwards(64), 128);
     /// ```
     fn align_upwards(self, align: Self) -> Self;
+
+    /// 将数值向上对齐到指定倍数，结果溢出或 `align` 为 `0` 时返回 `None`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pavo_traits::{AlignUpwards};
+    /// assert_eq!(63u8.checked_align_upwards(64), Some(64));
+    /// assert_eq!(250u8.checked_align_upwards(64), None);
+    /// assert_eq!(63u8.checked_align_upwards(0), None);
+    /// ```
+    fn checked_align_upwards(self, align: Self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// 将数值向上对齐到指定倍数，结果超出类型范围时返回 `Self::MAX`。
+    ///
+    /// 与 [align_upwards] 相同，`align` 为 `0` 时会 panic。
+    ///
+    /// [align_upwards]: #tymethod.align_upwards
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pavo_traits::{AlignUpwards};
+    /// assert_eq!(63u8.saturating_align_upwards(64), 64);
+    /// assert_eq!(250u8.saturating_align_upwards(64), u8::MAX);
+    /// ```
+    fn saturating_align_upwards(self, align: Self) -> Self;
+
+    /// 将数值向上对齐到指定倍数，`align` 为 `0` 时返回 `None`。
+    ///
+    /// 结果溢出时的行为与 [align_upwards] 相同，需要同时检查溢出时请使用
+    /// [checked_align_upwards]。
+    ///
+    /// [align_upwards]: #tymethod.align_upwards
+    /// [checked_align_upwards]: #tymethod.checked_align_upwards
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pavo_traits::{AlignUpwards};
+    /// assert_eq!(65.try_align_upwards(64), Some(128));
+    /// assert_eq!(65.try_align_upwards(0), None);
+    /// ```
+    fn try_align_upwards(self, align: Self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// 将数值向上对齐到指定的 2 的幂倍数，使用位掩码代替取余运算。
+    ///
+    /// `align` 必须为 2 的幂，调试模式下会进行断言；结果溢出时的行为与
+    /// [align_upwards] 相同。
+    ///
+    /// [align_upwards]: #tymethod.align_upwards
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pavo_traits::{AlignUpwards};
+    /// assert_eq!(65u32.align_upwards_pow2(64), 128);
+    /// assert_eq!(64u32.align_upwards_pow2(64), 64);
+    /// ```
+    fn align_upwards_pow2(self, align: Self) -> Self;
 }
 
 macro_rules! impl_align_downwards {
@@ -35,6 +145,22 @@ macro_rules! impl_align_downwards {
             fn align_downwards(self, align: Self) -> Self {
                 self - (self % align)
             }
+
+            fn try_align_downwards(self, align: Self) -> Option<Self> {
+                if align == 0 {
+                    None
+                } else {
+                    Some(self.align_downwards(align))
+                }
+            }
+
+            fn align_downwards_pow2(self, align: Self) -> Self {
+                debug_assert!(
+                    align > 0 && align & (align - 1) == 0,
+                    "align_downwards_pow2: align must be a power of two"
+                );
+                self & !(align - 1)
+            }
         }
     };
 }
@@ -49,6 +175,40 @@ macro_rules! impl_align_upwards {
                     self
                 }
             }
+
+            fn checked_align_upwards(self, align: Self) -> Option<Self> {
+                let rem = self.checked_rem(align)?;
+                if rem != 0 {
+                    self.checked_sub(rem)?.checked_add(align)
+                } else {
+                    Some(self)
+                }
+            }
+
+            fn saturating_align_upwards(self, align: Self) -> Self {
+                let rem = self % align;
+                if rem != 0 {
+                    (self - rem).checked_add(align).unwrap_or(Self::MAX)
+                } else {
+                    self
+                }
+            }
+
+            fn try_align_upwards(self, align: Self) -> Option<Self> {
+                if align == 0 {
+                    None
+                } else {
+                    Some(self.align_upwards(align))
+                }
+            }
+
+            fn align_upwards_pow2(self, align: Self) -> Self {
+                debug_assert!(
+                    align > 0 && align & (align - 1) == 0,
+                    "align_upwards_pow2: align must be a power of two"
+                );
+                (self + (align - 1)) & !(align - 1)
+            }
         }
     };
 }
@@ -79,6 +239,8 @@ impl_align_upwards!(usize);
 pub trait Clamped {
     /// 将当前值现在 `[min, max]` 的范围之内。
     ///
+    /// `min` 必须不大于 `max`，否则在调试模式下 panic。
+    ///
     /// # Examples
     ///
     /// ```
@@ -96,6 +258,11 @@ macro_rules! impl_clamped {
     ($Type:ty) => {
         impl Clamped for $Type {
             fn clamped(self, min: Self, max: Self) -> Self {
+                // 以 `partial_cmp` 比较，浮点边界为 NaN 时不会误报。
+                debug_assert!(
+                    min.partial_cmp(&max) != Some(core::cmp::Ordering::Greater),
+                    "clamped: min must be <= max"
+                );
                 if self < min {
                     return min;
                 }
@@ -121,9 +288,186 @@ impl_clamped!(usize);
 impl_clamped!(f32);
 impl_clamped!(f64);
 
+/// 定义将浮点数限制在 `[0, 1]` 范围内的契定。
+pub trait Clamped01 {
+    /// 将当前值限制在 `[0, 1]` 的范围之内，等价于 `clamped(0.0, 1.0)`。
+    ///
+    /// 与 [Clamped::clamped] 不同，NaN 会被视为 `0.0`，以保证结果总是有效的归一化值。
+    ///
+    /// [Clamped::clamped]: trait.Clamped.html#tymethod.clamped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Clamped01};
+    ///
+    /// assert_eq!(1.5f32.clamped01(), 1.0);
+    /// assert_eq!((-0.5f64).clamped01(), 0.0);
+    /// assert_eq!(f32::NAN.clamped01(), 0.0);
+    /// ```
+    fn clamped01(self) -> Self;
+}
+
+macro_rules! impl_clamped01 {
+    ($Type:ty) => {
+        impl Clamped01 for $Type {
+            fn clamped01(self) -> Self {
+                if self.is_nan() {
+                    0.0
+                } else {
+                    self.clamped(0.0, 1.0)
+                }
+            }
+        }
+    };
+}
+
+impl_clamped01!(f32);
+impl_clamped01!(f64);
+
+/// 定义将值循环折回指定范围内的契定。
+pub trait WrapInRange {
+    /// 将当前值循环折回半开区间 `[min, max)` 之内，超出部分从另一端继续计数。
+    ///
+    /// 常用于角度归一化与环形缓冲区索引；`max` 不大于 `min`（或浮点边界为 NaN）时返回 `min`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{WrapInRange};
+    ///
+    /// assert_eq!(370.wrap_in_range(0, 360), 10);
+    /// assert_eq!((-10).wrap_in_range(0, 360), 350);
+    /// assert_eq!(540.0f32.wrap_in_range(-180.0, 180.0), -180.0);
+    /// assert_eq!(5.wrap_in_range(3, 3), 3);
+    /// ```
+    fn wrap_in_range(self, min: Self, max: Self) -> Self;
+}
+
+macro_rules! impl_wrap_in_range {
+    ($Type:ty) => {
+        impl WrapInRange for $Type {
+            fn wrap_in_range(self, min: Self, max: Self) -> Self {
+                if max <= min {
+                    return min;
+                }
+                let span = max as i128 - min as i128;
+                (min as i128 + (self as i128 - min as i128).rem_euclid(span)) as Self
+            }
+        }
+    };
+}
+
+macro_rules! impl_wrap_in_range_float {
+    ($Type:ty) => {
+        impl WrapInRange for $Type {
+            fn wrap_in_range(self, min: Self, max: Self) -> Self {
+                if max <= min || max.is_nan() || min.is_nan() {
+                    return min;
+                }
+                let span = max - min;
+                // 与 `rem_euclid` 相同的计算方式，但不依赖 `std`。
+                let mut r = (self - min) % span;
+                if r < 0.0 {
+                    r += span;
+                }
+                // 极小的负余数加上区间长度后可能舍入为区间长度本身。
+                if r >= span {
+                    r = 0.0;
+                }
+                min + r
+            }
+        }
+    };
+}
+
+impl_wrap_in_range!(i8);
+impl_wrap_in_range!(i16);
+impl_wrap_in_range!(i32);
+impl_wrap_in_range!(i64);
+impl_wrap_in_range!(isize);
+impl_wrap_in_range!(u8);
+impl_wrap_in_range!(u16);
+impl_wrap_in_range!(u32);
+impl_wrap_in_range!(u64);
+impl_wrap_in_range!(usize);
+impl_wrap_in_range_float!(f32);
+impl_wrap_in_range_float!(f64);
+
+/// 定义将值从一个区间线性映射至另一区间的契定。
+pub trait MapRange: Sized {
+    /// 将当前值从 `from` 区间线性映射至 `to` 区间，
+    /// 即 `to.0 + (self - from.0) * (to.1 - to.0) / (from.1 - from.0)`。
+    ///
+    /// 不会对结果进行限制，需要时可配合 [Clamped] 使用；`from.0 == from.1` 时返回 `to.0`。
+    /// 整数类型的结果按四舍五入取整，超出类型范围时取类型的边界值。
+    ///
+    /// 整数类型通过 `f64` 计算，对于 64 位整数，绝对值超过 `2^53` 的输入或结果会损失精度。
+    ///
+    /// [Clamped]: trait.Clamped.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{MapRange};
+    ///
+    /// assert_eq!(511.5f32.map_range((0.0, 1023.0), (0.0, 1.0)), 0.5);
+    /// assert_eq!(5.map_range((0, 10), (100, 0)), 50);
+    /// assert_eq!(2u8.map_range((0, 3), (0, 255)), 170);
+    /// assert_eq!(7.0f64.map_range((1.0, 1.0), (2.0, 3.0)), 2.0);
+    /// ```
+    fn map_range(self, from: (Self, Self), to: (Self, Self)) -> Self;
+}
+
+macro_rules! impl_map_range {
+    ($Type:ty) => {
+        impl MapRange for $Type {
+            fn map_range(self, from: (Self, Self), to: (Self, Self)) -> Self {
+                if from.0 == from.1 {
+                    return to.0;
+                }
+                let v = to.0 as f64
+                    + (self as f64 - from.0 as f64) * (to.1 as f64 - to.0 as f64)
+                        / (from.1 as f64 - from.0 as f64);
+                v.round() as Self
+            }
+        }
+    };
+}
+
+macro_rules! impl_map_range_float {
+    ($Type:ty) => {
+        impl MapRange for $Type {
+            fn map_range(self, from: (Self, Self), to: (Self, Self)) -> Self {
+                if from.0 == from.1 {
+                    return to.0;
+                }
+                to.0 + (self - from.0) * (to.1 - to.0) / (from.1 - from.0)
+            }
+        }
+    };
+}
+
+impl_map_range!(i8);
+impl_map_range!(i16);
+impl_map_range!(i32);
+impl_map_range!(i64);
+impl_map_range!(isize);
+impl_map_range!(u8);
+impl_map_range!(u16);
+impl_map_range!(u32);
+impl_map_range!(u64);
+impl_map_range!(usize);
+impl_map_range_float!(f32);
+impl_map_range_float!(f64);
+
 /// 定义判断值是否相近的契定。
 pub trait IsApproach {
-    /// 当值处于 `+/- factor` 的范围内时返回 `true`。
+    /// 当两值的差不超过两者中绝对值较大者的 `factor` 倍时返回 `true`，
+    /// 即 `|self - target| <= factor * max(|self|, |target|)`。
+    ///
+    /// 判断是对称的，交换 `self` 与 `target` 结果不变；两值均为 `0` 时返回 `true`，
+    /// 任一值为 NaN 时返回 `false`。
     ///
     /// # Examples
     ///
@@ -136,6 +480,7 @@ pub trait IsApproach {
     /// assert!(9.is_approach(10, 0.1));
     /// assert!(!80.is_approach(100, 0.1));
     /// assert!(90.is_approach(100, 0.1));
+    /// assert!(0.75f32.is_approach(1.0, 0.25));
     /// ```
     fn is_approach(&self, target: Self, factor: f32) -> bool;
 }
@@ -144,8 +489,21 @@ macro_rules! impl_is_approach {
     ($Type:ty) => {
         impl IsApproach for $Type {
             fn is_approach(&self, target: Self, factor: f32) -> bool {
-                let diff = (target as f64 * factor as f64).ceil() as Self;
-                *self < target.saturating_add(diff) && *self > target.saturating_sub(diff)
+                let diff = (*self as i128 - target as i128).unsigned_abs() as f64;
+                let scale = (*self as i128)
+                    .unsigned_abs()
+                    .max((target as i128).unsigned_abs()) as f64;
+                diff <= factor as f64 * scale
+            }
+        }
+    };
+}
+
+macro_rules! impl_is_approach_float {
+    ($Type:ty) => {
+        impl IsApproach for $Type {
+            fn is_approach(&self, target: Self, factor: f32) -> bool {
+                (*self - target).abs() <= factor as Self * self.abs().max(target.abs())
             }
         }
     };
@@ -161,6 +519,59 @@ impl_is_approach!(u16);
 impl_is_approach!(u32);
 impl_is_approach!(u64);
 impl_is_approach!(usize);
+impl_is_approach_float!(f32);
+impl_is_approach_float!(f64);
+
+/// 定义按绝对容差判断两值是否接近的契定。
+pub trait IsNear {
+    /// 当 `|self - target| <= tolerance` 时返回 `true`。
+    ///
+    /// 整数按不会溢出的绝对差进行比较；浮点数任一值为 NaN 时返回 `false`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{IsNear};
+    ///
+    /// assert!(98.is_near(100, 2));
+    /// assert!(!97.is_near(100, 2));
+    /// assert!(0.1f64.is_near(0.1 + 1e-12, 1e-9));
+    /// ```
+    fn is_near(&self, target: Self, tolerance: Self) -> bool;
+}
+
+macro_rules! impl_is_near {
+    ($Type:ty) => {
+        impl IsNear for $Type {
+            fn is_near(&self, target: Self, tolerance: Self) -> bool {
+                (*self as i128 - target as i128).abs() <= tolerance as i128
+            }
+        }
+    };
+}
+
+macro_rules! impl_is_near_float {
+    ($Type:ty) => {
+        impl IsNear for $Type {
+            fn is_near(&self, target: Self, tolerance: Self) -> bool {
+                (*self - target).abs() <= tolerance
+            }
+        }
+    };
+}
+
+impl_is_near!(i8);
+impl_is_near!(i16);
+impl_is_near!(i32);
+impl_is_near!(i64);
+impl_is_near!(isize);
+impl_is_near!(u8);
+impl_is_near!(u16);
+impl_is_near!(u32);
+impl_is_near!(u64);
+impl_is_near!(usize);
+impl_is_near_float!(f32);
+impl_is_near_float!(f64);
 
 /// 定义判断值是否在范围内的契定。
 pub trait IsInRange {
@@ -176,7 +587,35 @@ pub trait IsInRange {
     /// assert!(8.is_in_range(8, 9));
     /// assert!(!8.is_in_range(9, 10));
     /// ```
+    #[allow(clippy::wrong_self_convention)]
     fn is_in_range(self, min: Self, max: Self) -> bool;
+
+    /// 当值处于半开区间 `[min, max)` 内时返回 `true`，即包含 `min` 而不包含 `max`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{IsInRange};
+    ///
+    /// assert!(7.is_in_range_exclusive(7, 8));
+    /// assert!(!8.is_in_range_exclusive(7, 8));
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_in_range_exclusive(self, min: Self, max: Self) -> bool;
+
+    /// 当值处于开区间 `(min, max)` 内时返回 `true`，即两端均不包含。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{IsInRange};
+    ///
+    /// assert!(8.is_in_open_range(7, 9));
+    /// assert!(!7.is_in_open_range(7, 9));
+    /// assert!(!9.is_in_open_range(7, 9));
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_in_open_range(self, min: Self, max: Self) -> bool;
 }
 
 macro_rules! impl_is_in_range {
@@ -185,6 +624,14 @@ macro_rules! impl_is_in_range {
             fn is_in_range(self, min: Self, max: Self) -> bool {
                 self >= min && self <= max
             }
+
+            fn is_in_range_exclusive(self, min: Self, max: Self) -> bool {
+                self >= min && self < max
+            }
+
+            fn is_in_open_range(self, min: Self, max: Self) -> bool {
+                self > min && self < max
+            }
         }
     };
 }
@@ -202,31 +649,2637 @@ impl_is_in_range!(usize);
 impl_is_in_range!(f32);
 impl_is_in_range!(f64);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// 定义按透明度混合两个数值的契定。
+pub trait Blend {
+    /// 按透明度 `alpha` 将当前值与 `other` 混合，即 `self * (1 - alpha) + other * alpha`。
+    ///
+    /// `alpha` 会被限制在 `[0, 1]` 的范围之内，NaN 视为 `0` 即返回 `self`；
+    /// 整数类型的结果按四舍五入取整。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Blend};
+    ///
+    /// assert_eq!(0u8.blend(255, 0.5), 128);
+    /// assert_eq!(10u8.blend(20, 2.0), 20);
+    /// assert_eq!(1.0f32.blend(3.0, 0.25), 1.5);
+    /// assert_eq!(200u8.blend(100, f32::NAN), 200);
+    /// ```
+    fn blend(self, other: Self, alpha: f32) -> Self;
+}
 
-    #[test]
-    fn test_is_approach() {
-        assert_eq!(1000u32.is_approach(1000, 0.1), true);
-        assert_eq!(900u32.is_approach(1000, 0.099), false);
-        assert_eq!(900u32.is_approach(1000, 0.1), true);
-        assert_eq!(900u32.is_approach(1000, 0.2), true);
-        assert_eq!(800u32.is_approach(1000, 0.2), true);
-        assert_eq!(700u32.is_approach(1000, 0.2), false);
-        assert_eq!(600u32.is_approach(1000, 0.2), false);
-        assert_eq!(0u32.is_approach(1000, 0.2), false);
-        let a = std::u32::MAX - std::u32::MAX / 1000 * 99;
-        assert_eq!(a.is_approach(std::u32::MAX, 0.2), true);
-    }
+macro_rules! impl_blend {
+    ($Type:ty) => {
+        impl Blend for $Type {
+            fn blend(self, other: Self, alpha: f32) -> Self {
+                let alpha = alpha.clamped01() as f64;
+                if alpha == 0.0 {
+                    return self;
+                }
+                (self as f64 * (1.0 - alpha) + other as f64 * alpha).round() as Self
+            }
+        }
+    };
+}
 
-    #[test]
-    fn test_is_in_range() {
-        for a in 0..1000000 {
-            assert!(!a.is_in_range(a - 2, a - 1));
-            assert!(a.is_in_range(a - 1, a));
-            assert!(a.is_in_range(a, a));
-            assert!(a.is_in_range(a, a + 1));
+macro_rules! impl_blend_float {
+    ($Type:ty) => {
+        impl Blend for $Type {
+            fn blend(self, other: Self, alpha: f32) -> Self {
+                let alpha = alpha.clamped01() as Self;
+                self * (1.0 - alpha) + other * alpha
+            }
         }
+    };
+}
+
+impl_blend!(i8);
+impl_blend!(i16);
+impl_blend!(i32);
+impl_blend!(i64);
+impl_blend!(isize);
+impl_blend!(u8);
+impl_blend!(u16);
+impl_blend!(u32);
+impl_blend!(u64);
+impl_blend!(usize);
+impl_blend_float!(f32);
+impl_blend_float!(f64);
+
+/// 定义 8 位通道整数透明度混合的契定。
+pub trait AlphaBlendU8 {
+    /// 按透明度 `alpha` 将 `over` 叠加在当前值之上，
+    /// 即 `(self * (255 - alpha) + over * alpha + 127) / 255`。
+    ///
+    /// 除以 `255` 并加上 `127` 进行四舍五入，避免以 `>> 8` 近似时的偏差，
+    /// `alpha` 为 `0` 与 `255` 时分别精确返回 `self` 与 `over`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{AlphaBlendU8};
+    ///
+    /// assert_eq!(0u8.alpha_blend_u8(255, 128), 128);
+    /// assert_eq!(200u8.alpha_blend_u8(10, 0), 200);
+    /// assert_eq!(200u8.alpha_blend_u8(10, 255), 10);
+    /// ```
+    fn alpha_blend_u8(self, over: u8, alpha: u8) -> u8;
+}
+
+impl AlphaBlendU8 for u8 {
+    fn alpha_blend_u8(self, over: u8, alpha: u8) -> u8 {
+        let alpha = alpha as u32;
+        ((self as u32 * (255 - alpha) + over as u32 * alpha + 127) / 255) as u8
+    }
+}
+
+/// 定义线性插值的契定。
+pub trait Lerp {
+    /// 按 `t` 在当前值与 `other` 之间线性插值，即 `self + (other - self) * t`。
+    ///
+    /// `t` 为 `0` 时返回 `self`，为 `1` 时返回 `other`；超出 `[0, 1]` 时按直线外推而不进行限制，
+    /// 需要限制时可先对 `t` 调用 [Clamped::clamped]。整数类型的结果按四舍五入（远离零）取整，
+    /// 超出类型范围时取类型的边界值。
+    ///
+    /// 整数类型在 `t` 为 `0` 或 `1` 时精确返回端点；其余位置通过 `f64` 计算，
+    /// 对于 64 位整数，绝对值超过 `2^53` 的端点或结果会损失精度。
+    ///
+    /// [Clamped::clamped]: trait.Clamped.html#tymethod.clamped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Lerp};
+    ///
+    /// assert_eq!(1.0f32.lerp(3.0, 0.5), 2.0);
+    /// assert_eq!(0.lerp(3, 0.5), 2);
+    /// assert_eq!(10.lerp(20, 1.5), 25);
+    /// ```
+    fn lerp(self, other: Self, t: f32) -> Self;
+}
+
+macro_rules! impl_lerp {
+    ($Type:ty) => {
+        impl Lerp for $Type {
+            fn lerp(self, other: Self, t: f32) -> Self {
+                if t == 0.0 {
+                    return self;
+                }
+                if t == 1.0 {
+                    return other;
+                }
+                (self as f64 + (other as f64 - self as f64) * t as f64).round() as Self
+            }
+        }
+    };
+}
+
+macro_rules! impl_lerp_float {
+    ($Type:ty) => {
+        impl Lerp for $Type {
+            fn lerp(self, other: Self, t: f32) -> Self {
+                self + (other - self) * t as Self
+            }
+        }
+    };
+}
+
+impl_lerp!(i8);
+impl_lerp!(i16);
+impl_lerp!(i32);
+impl_lerp!(i64);
+impl_lerp!(isize);
+impl_lerp!(u8);
+impl_lerp!(u16);
+impl_lerp!(u32);
+impl_lerp!(u64);
+impl_lerp!(usize);
+impl_lerp_float!(f32);
+impl_lerp_float!(f64);
+
+/// 生成从 `start` 到 `end` （不含）之间按 `step` 递增的对齐偏移序列。
+///
+/// 首个偏移为 `start` 向上对齐到 `align` 的值，`step` 本身也会被向上对齐到 `align`。
+/// 当对齐后的 `step` 为 `0` 时，最多只产生一个偏移。
+///
+/// # Panics
+///
+/// `align` 为 `0` 时触发除零异常。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{aligned_steps};
+///
+/// let v: Vec<usize> = aligned_steps(5, 100, 32, 16).collect();
+/// assert_eq!(v, [16, 48, 80]);
+/// let v: Vec<usize> = aligned_steps(5, 100, 0, 16).collect();
+/// assert_eq!(v, [16]);
+/// ```
+pub fn aligned_steps(
+    start: usize,
+    end: usize,
+    step: usize,
+    align: usize,
+) -> impl Iterator<Item = usize> {
+    let first = start.align_upwards(align);
+    let step = step.align_upwards(align);
+    core::iter::successors(Some(first), move |&x| {
+        if step == 0 {
+            None
+        } else {
+            x.checked_add(step)
+        }
+    })
+    .take_while(move |&x| x < end)
+}
+
+/// 定义计算汉明距离的契定。
+pub trait HammingDistance {
+    /// 返回两个值之间不同的比特位数。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{HammingDistance};
+    ///
+    /// assert_eq!(0b1010u8.hamming_distance(0b0110), 2);
+    /// assert_eq!(u32::MAX.hamming_distance(0), 32);
+    /// ```
+    fn hamming_distance(self, other: Self) -> u32;
+}
+
+macro_rules! impl_hamming_distance {
+    ($Type:ty) => {
+        impl HammingDistance for $Type {
+            fn hamming_distance(self, other: Self) -> u32 {
+                (self ^ other).count_ones()
+            }
+        }
+    };
+}
+
+impl_hamming_distance!(u8);
+impl_hamming_distance!(u16);
+impl_hamming_distance!(u32);
+impl_hamming_distance!(u64);
+impl_hamming_distance!(usize);
+
+/// 定义将值吸附到网格的契定。
+pub trait SnapToGrid {
+    /// 将当前值吸附到最近的 `origin + n * spacing` 网格点上。
+    ///
+    /// `spacing` 不大于 `0` 时原样返回。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{SnapToGrid};
+    ///
+    /// assert_eq!(7.0f32.snap_to_grid(5.0, 1.0), 6.0);
+    /// assert_eq!(9.0f64.snap_to_grid(5.0, 1.0), 11.0);
+    /// assert_eq!(7.0f32.snap_to_grid(0.0, 1.0), 7.0);
+    /// ```
+    fn snap_to_grid(self, spacing: Self, origin: Self) -> Self;
+}
+
+macro_rules! impl_snap_to_grid {
+    ($Type:ty) => {
+        impl SnapToGrid for $Type {
+            fn snap_to_grid(self, spacing: Self, origin: Self) -> Self {
+                if spacing <= 0.0 || spacing.is_nan() {
+                    return self;
+                }
+                origin + ((self - origin) / spacing).round() * spacing
+            }
+        }
+    };
+}
+
+impl_snap_to_grid!(f32);
+impl_snap_to_grid!(f64);
+
+/// 定义向上取整除法的契定。
+pub trait DivCeil {
+    /// 返回 `self / rhs` 向正无穷方向取整的结果，计算过程不会产生中间溢出。
+    ///
+    /// 方法名有意避开标准库整数类型的固有方法 `div_ceil`，以免方法调用被其遮蔽。
+    ///
+    /// # Panics
+    ///
+    /// `rhs` 为 `0` 时 panic；有符号类型的 `MIN / -1` 同样会因溢出而 panic。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{DivCeil};
+    ///
+    /// assert_eq!(10u32.ceil_div(4), 3);
+    /// assert_eq!(u8::MAX.ceil_div(2), 128);
+    /// assert_eq!((-7i32).ceil_div(2), -3);
+    /// ```
+    fn ceil_div(self, rhs: Self) -> Self;
+}
+
+/// 定义四舍五入除法的契定。
+pub trait DivRound {
+    /// 返回 `self / rhs` 四舍五入（远离零）的结果，计算过程不会产生中间溢出。
+    ///
+    /// # Panics
+    ///
+    /// `rhs` 为 `0` 时 panic；有符号类型的 `MIN / -1` 同样会因溢出而 panic。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{DivRound};
+    ///
+    /// assert_eq!(10u32.div_round(4), 3);
+    /// assert_eq!(9u32.div_round(4), 2);
+    /// assert_eq!((-10i32).div_round(4), -3);
+    /// ```
+    fn div_round(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_ceil_div {
+    ($Type:ty) => {
+        impl DivCeil for $Type {
+            fn ceil_div(self, rhs: Self) -> Self {
+                assert!(rhs != 0, "ceil_div: divisor must be non-zero");
+                let q = self / rhs;
+                let r = self % rhs;
+                if r != 0 && (r > 0) == (rhs > 0) {
+                    q + 1
+                } else {
+                    q
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_div_round {
+    ($Type:ty) => {
+        impl DivRound for $Type {
+            fn div_round(self, rhs: Self) -> Self {
+                assert!(rhs != 0, "div_round: divisor must be non-zero");
+                let q = self / rhs;
+                let r = self % rhs;
+                if r >= rhs - r {
+                    q + 1
+                } else {
+                    q
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_div_round_signed {
+    ($Type:ty) => {
+        impl DivRound for $Type {
+            fn div_round(self, rhs: Self) -> Self {
+                assert!(rhs != 0, "div_round: divisor must be non-zero");
+                let q = self / rhs;
+                let r = self % rhs;
+                let (ar, br) = (r.unsigned_abs(), rhs.unsigned_abs());
+                if r == 0 || ar < br - ar {
+                    q
+                } else if (r < 0) == (rhs < 0) {
+                    q + 1
+                } else {
+                    q - 1
+                }
+            }
+        }
+    };
+}
+
+impl_ceil_div!(i8);
+impl_ceil_div!(i16);
+impl_ceil_div!(i32);
+impl_ceil_div!(i64);
+impl_ceil_div!(isize);
+impl_ceil_div!(u8);
+impl_ceil_div!(u16);
+impl_ceil_div!(u32);
+impl_ceil_div!(u64);
+impl_ceil_div!(usize);
+
+impl_div_round_signed!(i8);
+impl_div_round_signed!(i16);
+impl_div_round_signed!(i32);
+impl_div_round_signed!(i64);
+impl_div_round_signed!(isize);
+impl_div_round!(u8);
+impl_div_round!(u16);
+impl_div_round!(u32);
+impl_div_round!(u64);
+impl_div_round!(usize);
+
+/// 定义判断数值是否为 2 的幂的契定。
+pub trait IsPowerOfTwo {
+    /// 当数值为 2 的幂时返回 `true`，`0` 不是 2 的幂。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{IsPowerOfTwo};
+    ///
+    /// assert!(IsPowerOfTwo::is_power_of_two(64u32));
+    /// assert!(!IsPowerOfTwo::is_power_of_two(0u32));
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_power_of_two(self) -> bool;
+}
+
+/// 定义求不小于当前值的最小 2 的幂的契定。
+pub trait NextPowerOfTwo: Sized {
+    /// 返回不小于当前值的最小 2 的幂，结果超出类型范围时返回 `None`。
+    ///
+    /// `0` 的结果为 `1`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{NextPowerOfTwo};
+    ///
+    /// assert_eq!(1000u32.next_pow2(), Some(1024));
+    /// assert_eq!(0u8.next_pow2(), Some(1));
+    /// assert_eq!(200u8.next_pow2(), None);
+    /// ```
+    fn next_pow2(self) -> Option<Self>;
+}
+
+/// 定义求不大于当前值的最大 2 的幂的契定。
+pub trait PrevPowerOfTwo: Sized {
+    /// 返回不大于当前值的最大 2 的幂，当前值为 `0` 时返回 `None`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{PrevPowerOfTwo};
+    ///
+    /// assert_eq!(1000u32.prev_pow2(), Some(512));
+    /// assert_eq!(1024u32.prev_pow2(), Some(1024));
+    /// assert_eq!(0u32.prev_pow2(), None);
+    /// ```
+    fn prev_pow2(self) -> Option<Self>;
+}
+
+macro_rules! impl_power_of_two {
+    ($Type:ty) => {
+        impl IsPowerOfTwo for $Type {
+            fn is_power_of_two(self) -> bool {
+                self.count_ones() == 1
+            }
+        }
+
+        impl NextPowerOfTwo for $Type {
+            fn next_pow2(self) -> Option<Self> {
+                self.checked_next_power_of_two()
+            }
+        }
+
+        impl PrevPowerOfTwo for $Type {
+            fn prev_pow2(self) -> Option<Self> {
+                if self == 0 {
+                    None
+                } else {
+                    Some(1 << (<$Type>::BITS - 1 - self.leading_zeros()))
+                }
+            }
+        }
+    };
+}
+
+impl_power_of_two!(u8);
+impl_power_of_two!(u16);
+impl_power_of_two!(u32);
+impl_power_of_two!(u64);
+impl_power_of_two!(usize);
+
+/// 定义按最大步长向目标值逼近的契定。
+pub trait SlewLimit {
+    /// 将当前值向 `target` 移动至多 `max_step`，两者之差不超过 `max_step` 时直接返回 `target`。
+    ///
+    /// 每个周期调用一次即可实现斜率限制；`max_step` 为负数时视为 `0`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{SlewLimit};
+    ///
+    /// assert_eq!(0.slew_limit(100, 10), 10);
+    /// assert_eq!(95.slew_limit(100, 10), 100);
+    /// assert_eq!(1.0f32.slew_limit(0.0, 0.25), 0.75);
+    /// ```
+    fn slew_limit(self, target: Self, max_step: Self) -> Self;
+}
+
+macro_rules! impl_slew_limit {
+    ($Type:ty) => {
+        impl SlewLimit for $Type {
+            fn slew_limit(self, target: Self, max_step: Self) -> Self {
+                let diff = target as i128 - self as i128;
+                let step = (max_step as i128).max(0);
+                (self as i128 + diff.max(-step).min(step)) as Self
+            }
+        }
+    };
+}
+
+macro_rules! impl_slew_limit_float {
+    ($Type:ty) => {
+        impl SlewLimit for $Type {
+            fn slew_limit(self, target: Self, max_step: Self) -> Self {
+                let step = max_step.max(0.0);
+                let diff = target - self;
+                if diff > step {
+                    self + step
+                } else if diff < -step {
+                    self - step
+                } else {
+                    target
+                }
+            }
+        }
+    };
+}
+
+impl_slew_limit!(i8);
+impl_slew_limit!(i16);
+impl_slew_limit!(i32);
+impl_slew_limit!(i64);
+impl_slew_limit!(isize);
+impl_slew_limit!(u8);
+impl_slew_limit!(u16);
+impl_slew_limit!(u32);
+impl_slew_limit!(u64);
+impl_slew_limit!(usize);
+impl_slew_limit_float!(f32);
+impl_slew_limit_float!(f64);
+
+/// 定义计算定点数倒数的契定。
+pub trait ReciprocalQ {
+    /// 返回 `(1 << frac_bits) / self`，即具有 `frac_bits` 位小数的定点倒数。
+    ///
+    /// 当 `self` 为 `0` 或结果超出类型范围时返回 `Self::MAX`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{ReciprocalQ};
+    ///
+    /// let r = 4u32.reciprocal_q(16);
+    /// assert_eq!(r, 16384);
+    /// assert_eq!((1000 * r) >> 16, 250);
+    /// assert_eq!(0u32.reciprocal_q(16), u32::MAX);
+    /// ```
+    fn reciprocal_q(self, frac_bits: u32) -> Self;
+}
+
+macro_rules! impl_reciprocal_q {
+    ($Type:ty) => {
+        impl ReciprocalQ for $Type {
+            fn reciprocal_q(self, frac_bits: u32) -> Self {
+                if self == 0 || frac_bits >= 128 {
+                    return Self::MAX;
+                }
+                let r = (1u128 << frac_bits) / self as u128;
+                if r > Self::MAX as u128 {
+                    Self::MAX
+                } else {
+                    r as Self
+                }
+            }
+        }
+    };
+}
+
+impl_reciprocal_q!(u8);
+impl_reciprocal_q!(u16);
+impl_reciprocal_q!(u32);
+impl_reciprocal_q!(u64);
+impl_reciprocal_q!(usize);
+
+/// 定义将两个数值约分为最简比例的契定。
+pub trait ReducedRatio: Sized {
+    /// 返回 `(self, other)` 分别除以两者最大公约数后的结果。
+    ///
+    /// 其中一个为 `0` 时另一个约分为 `1`，两者都为 `0` 时原样返回。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{ReducedRatio};
+    ///
+    /// assert_eq!(1920u32.as_reduced_ratio(1080), (16, 9));
+    /// assert_eq!(0u32.as_reduced_ratio(5), (0, 1));
+    /// assert_eq!(0u32.as_reduced_ratio(0), (0, 0));
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn as_reduced_ratio(self, other: Self) -> (Self, Self);
+}
+
+macro_rules! impl_reduced_ratio {
+    ($Type:ty) => {
+        impl ReducedRatio for $Type {
+            fn as_reduced_ratio(self, other: Self) -> (Self, Self) {
+                let (mut a, mut b) = (self, other);
+                while b != 0 {
+                    let t = a % b;
+                    a = b;
+                    b = t;
+                }
+                if a == 0 {
+                    (self, other)
+                } else {
+                    (self / a, other / a)
+                }
+            }
+        }
+    };
+}
+
+impl_reduced_ratio!(u8);
+impl_reduced_ratio!(u16);
+impl_reduced_ratio!(u32);
+impl_reduced_ratio!(u64);
+impl_reduced_ratio!(usize);
+
+/// 定义秒数与帧数之间互相转换的契定。
+pub trait FrameCount {
+    /// 将秒数按帧率 `fps` 转换为四舍五入后的帧数。
+    ///
+    /// 负数或 `NaN` 转换为 `0`，超出范围时饱和至 `u64::MAX`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{FrameCount};
+    ///
+    /// assert_eq!(2.0.to_frames(30.0), 60);
+    /// assert_eq!(1.0.to_frames(29.97), 30);
+    /// assert_eq!((-1.0).to_frames(30.0), 0);
+    /// ```
+    fn to_frames(self, fps: f64) -> u64;
+
+    /// 将帧数按帧率 `fps` 转换为秒数，`fps` 不大于 `0` 时返回 `0`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{FrameCount};
+    ///
+    /// assert_eq!(f64::from_frames(60, 30.0), 2.0);
+    /// ```
+    fn from_frames(n: u64, fps: f64) -> Self;
+}
+
+impl FrameCount for f64 {
+    fn to_frames(self, fps: f64) -> u64 {
+        let frames = (self * fps).round();
+        if frames.is_nan() || frames <= 0.0 {
+            0
+        } else {
+            frames as u64
+        }
+    }
+
+    fn from_frames(n: u64, fps: f64) -> Self {
+        if fps > 0.0 {
+            n as f64 / fps
+        } else {
+            0.0
+        }
+    }
+}
+
+/// 定义伽马校正的契定。
+///
+/// 需要启用 `std` 特性。
+#[cfg(feature = "std")]
+pub trait Gamma {
+    /// 将 `[0, 1]` 范围内的线性值按 `gamma` 编码，即 `self.powf(1 / gamma)`。
+    ///
+    /// 输入会先被限制在 `[0, 1]` 之内，`gamma` 不大于 `0` 时原样返回。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Gamma};
+    ///
+    /// assert_eq!(0.25f32.apply_gamma(2.0), 0.5);
+    /// assert_eq!(2.0f32.apply_gamma(2.2), 1.0);
+    /// ```
+    fn apply_gamma(self, gamma: f32) -> Self;
+
+    /// 将按 `gamma` 编码的值还原为线性值，即 `self.powf(gamma)`。
+    ///
+    /// 输入会先被限制在 `[0, 1]` 之内，`gamma` 不大于 `0` 时原样返回。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Gamma};
+    ///
+    /// assert_eq!(0.5f32.remove_gamma(2.0), 0.25);
+    /// ```
+    fn remove_gamma(self, gamma: f32) -> Self;
+}
+
+macro_rules! impl_gamma {
+    ($Type:ty) => {
+        #[cfg(feature = "std")]
+        impl Gamma for $Type {
+            fn apply_gamma(self, gamma: f32) -> Self {
+                if gamma <= 0.0 || gamma.is_nan() {
+                    return self;
+                }
+                self.clamped(0.0, 1.0).powf(1.0 / gamma as Self)
+            }
+
+            fn remove_gamma(self, gamma: f32) -> Self {
+                if gamma <= 0.0 || gamma.is_nan() {
+                    return self;
+                }
+                self.clamped(0.0, 1.0).powf(gamma as Self)
+            }
+        }
+    };
+}
+
+impl_gamma!(f32);
+impl_gamma!(f64);
+
+/// 定义幅值与分贝之间转换的契定。
+///
+/// 需要启用 `std` 特性。
+#[cfg(feature = "std")]
+pub trait Decibels {
+    /// 将幅值转换为分贝，即 `20 * log10(self)`。
+    ///
+    /// 结果最低限制为 `-120` dB，零、负数及 NaN 幅值同样返回 `-120` dB。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Decibels};
+    ///
+    /// assert_eq!(1.0f32.to_db(), 0.0);
+    /// assert!((0.5f32.to_db() + 6.0206).abs() < 1e-4);
+    /// assert_eq!(0.0f32.to_db(), -120.0);
+    /// ```
+    fn to_db(self) -> f32;
+
+    /// 将分贝转换为幅值，即 `10 ^ (db / 20)`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Decibels};
+    ///
+    /// assert_eq!(f32::from_db(0.0), 1.0);
+    /// assert_eq!(f64::from_db(-20.0), 0.1);
+    /// ```
+    fn from_db(db: f32) -> Self;
+}
+
+macro_rules! impl_decibels {
+    ($Type:ty) => {
+        #[cfg(feature = "std")]
+        impl Decibels for $Type {
+            fn to_db(self) -> f32 {
+                const FLOOR: f32 = -120.0;
+                if self > 0.0 {
+                    ((20.0 * self.log10()) as f32).max(FLOOR)
+                } else {
+                    FLOOR
+                }
+            }
+
+            fn from_db(db: f32) -> Self {
+                (10.0 as Self).powf(db as Self / 20.0)
+            }
+        }
+    };
+}
+
+impl_decibels!(f32);
+impl_decibels!(f64);
+
+/// 预先计算的 8 位伽马校正查找表。
+///
+/// 对大量像素进行相同的伽马校正时，可避免逐个调用 `powf`。需要启用 `std` 特性。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{GammaLut};
+///
+/// let lut = GammaLut::new(2.0);
+/// assert_eq!(lut.apply(0), 0);
+/// assert_eq!(lut.apply(64), 128);
+/// assert_eq!(lut.apply(255), 255);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct GammaLut {
+    table: [u8; 256],
+}
+
+#[cfg(feature = "std")]
+impl GammaLut {
+    /// 创建按 `gamma` 编码的查找表，每一项为 [Gamma::apply_gamma] 的结果按四舍五入量化。
+    ///
+    /// [Gamma::apply_gamma]: trait.Gamma.html#tymethod.apply_gamma
+    pub fn new(gamma: f32) -> Self {
+        let mut table = [0u8; 256];
+        for (i, v) in table.iter_mut().enumerate() {
+            *v = ((i as f32 / 255.0).apply_gamma(gamma) * 255.0).round() as u8;
+        }
+        Self { table }
+    }
+
+    /// 返回 `value` 经查找表校正后的值。
+    pub fn apply(&self, value: u8) -> u8 {
+        self.table[value as usize]
+    }
+}
+
+/// 定义计算内存区域所占页数的契定。
+pub trait PageCount {
+    /// 返回大小为 `self` 字节的区域占用的页数，即 `self.align_upwards(page_size) / page_size`。
+    ///
+    /// `page_size` 为 `0` 时返回 `0`，计算过程不会溢出。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{PageCount};
+    ///
+    /// assert_eq!(4097usize.page_count(4096), 2);
+    /// assert_eq!(4096usize.page_count(4096), 1);
+    /// assert_eq!(0usize.page_count(4096), 0);
+    /// ```
+    fn page_count(self, page_size: usize) -> usize;
+}
+
+impl PageCount for usize {
+    fn page_count(self, page_size: usize) -> usize {
+        if page_size == 0 || self == 0 {
+            return 0;
+        }
+        (self - 1) / page_size + 1
+    }
+}
+
+/// 定义带限幅的乘加运算契定。
+pub trait MulAddClamped {
+    /// 计算 `self * mul + add` 并将结果限制在 `[min, max]` 的范围之内。
+    ///
+    /// 中间结果使用 `i128` 饱和运算，不会在限幅前发生溢出回绕。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{MulAddClamped};
+    ///
+    /// assert_eq!(3i16.mul_add_clamped(4, 5, -100, 100), 17);
+    /// assert_eq!(i16::MAX.mul_add_clamped(i16::MAX, 1, i16::MIN, i16::MAX), i16::MAX);
+    /// ```
+    fn mul_add_clamped(self, mul: Self, add: Self, min: Self, max: Self) -> Self;
+}
+
+macro_rules! impl_mul_add_clamped {
+    ($Type:ty) => {
+        impl MulAddClamped for $Type {
+            fn mul_add_clamped(self, mul: Self, add: Self, min: Self, max: Self) -> Self {
+                let v = (self as i128)
+                    .saturating_mul(mul as i128)
+                    .saturating_add(add as i128);
+                v.max(min as i128).min(max as i128) as Self
+            }
+        }
+    };
+}
+
+impl_mul_add_clamped!(i8);
+impl_mul_add_clamped!(i16);
+impl_mul_add_clamped!(i32);
+impl_mul_add_clamped!(i64);
+impl_mul_add_clamped!(isize);
+impl_mul_add_clamped!(u8);
+impl_mul_add_clamped!(u16);
+impl_mul_add_clamped!(u32);
+impl_mul_add_clamped!(u64);
+impl_mul_add_clamped!(usize);
+
+/// 定义在不同位深之间缩放数值的契定。
+pub trait ReduceDepth {
+    /// 将 `from_bits` 位深的值按比例四舍五入缩放至 `to_bits` 位深。
+    ///
+    /// 同样适用于提升位深（如 5 位扩展到 8 位）；超出 `from_bits` 表示范围的输入先被限幅，
+    /// 结果超出类型范围时饱和至 `Self::MAX`，位深为 `0` 时返回 `0`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{ReduceDepth};
+    ///
+    /// assert_eq!(255u8.reduce_depth(8, 5), 31);
+    /// assert_eq!(31u8.reduce_depth(5, 8), 255);
+    /// assert_eq!(128u16.reduce_depth(8, 6), 32);
+    /// ```
+    fn reduce_depth(self, from_bits: u32, to_bits: u32) -> Self;
+}
+
+macro_rules! impl_reduce_depth {
+    ($Type:ty) => {
+        impl ReduceDepth for $Type {
+            fn reduce_depth(self, from_bits: u32, to_bits: u32) -> Self {
+                let max_of = |bits: u32| (1u128 << bits.min(64)) - 1;
+                let (max_from, max_to) = (max_of(from_bits), max_of(to_bits));
+                if max_from == 0 {
+                    return 0;
+                }
+                let v = (self as u128).min(max_from);
+                let r = (v * max_to + max_from / 2) / max_from;
+                if r > Self::MAX as u128 {
+                    Self::MAX
+                } else {
+                    r as Self
+                }
+            }
+        }
+    };
+}
+
+impl_reduce_depth!(u8);
+impl_reduce_depth!(u16);
+impl_reduce_depth!(u32);
+impl_reduce_depth!(u64);
+impl_reduce_depth!(usize);
+
+/// 定义带弹性边界的限幅契定。
+pub trait ClampElastic {
+    /// 将当前值弹性地限制在 `[min, max]` 的范围附近。
+    ///
+    /// 范围之内的值原样返回；越界距离为 `d` 时，结果为边界值向外偏移
+    /// `d / (1 + d * resistance)`，即越界时斜率为 `1`，越远阻力越大，
+    /// 最终渐近于边界外 `1 / resistance` 处。`resistance` 不大于 `0` 时不施加阻力。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{ClampElastic};
+    ///
+    /// assert_eq!(50.0f32.clamp_elastic(0.0, 100.0, 0.1), 50.0);
+    /// assert_eq!(110.0f32.clamp_elastic(0.0, 100.0, 0.1), 105.0);
+    /// assert_eq!((-10.0f32).clamp_elastic(0.0, 100.0, 0.1), -5.0);
+    /// ```
+    fn clamp_elastic(self, min: Self, max: Self, resistance: f32) -> Self;
+}
+
+macro_rules! impl_clamp_elastic {
+    ($Type:ty) => {
+        impl ClampElastic for $Type {
+            fn clamp_elastic(self, min: Self, max: Self, resistance: f32) -> Self {
+                if resistance <= 0.0 || resistance.is_nan() {
+                    return self;
+                }
+                let k = resistance as Self;
+                if self > max {
+                    let d = self - max;
+                    max + d / (1.0 + d * k)
+                } else if self < min {
+                    let d = min - self;
+                    min - d / (1.0 + d * k)
+                } else {
+                    self
+                }
+            }
+        }
+    };
+}
+
+impl_clamp_elastic!(f32);
+impl_clamp_elastic!(f64);
+
+/// 将 `x` 与 `y` 的比特位交错编码为 Morton（Z 序）码，`x` 占据偶数位，`y` 占据奇数位。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{morton_encode};
+///
+/// assert_eq!(morton_encode(0b11, 0b00), 0b0101);
+/// assert_eq!(morton_encode(0b00, 0b11), 0b1010);
+/// ```
+pub fn morton_encode(x: u32, y: u32) -> u64 {
+    fn spread(v: u32) -> u64 {
+        let mut v = v as u64;
+        v = (v | (v << 16)) & 0x0000_FFFF_0000_FFFF;
+        v = (v | (v << 8)) & 0x00FF_00FF_00FF_00FF;
+        v = (v | (v << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+        v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+        (v | (v << 1)) & 0x5555_5555_5555_5555
+    }
+    spread(x) | (spread(y) << 1)
+}
+
+/// 将 Morton（Z 序）码解码为 `(x, y)`，是 [morton_encode] 的逆运算。
+///
+/// [morton_encode]: fn.morton_encode.html
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{morton_decode, morton_encode};
+///
+/// assert_eq!(morton_decode(morton_encode(123, 456)), (123, 456));
+/// ```
+pub fn morton_decode(z: u64) -> (u32, u32) {
+    fn compact(v: u64) -> u32 {
+        let mut v = v & 0x5555_5555_5555_5555;
+        v = (v | (v >> 1)) & 0x3333_3333_3333_3333;
+        v = (v | (v >> 2)) & 0x0F0F_0F0F_0F0F_0F0F;
+        v = (v | (v >> 4)) & 0x00FF_00FF_00FF_00FF;
+        v = (v | (v >> 8)) & 0x0000_FFFF_0000_FFFF;
+        ((v | (v >> 16)) & 0xFFFF_FFFF) as u32
+    }
+    (compact(z), compact(z >> 1))
+}
+
+/// 定义计算两个对齐偏移之差的契定。
+pub trait AlignedDelta: Sized {
+    /// 当 `self` 与 `other` 均对齐到 `align` 且 `self >= other` 时返回 `self - other`。
+    ///
+    /// 不满足上述条件、`align` 为 `0` 或结果溢出时返回 `None`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{AlignedDelta};
+    ///
+    /// assert_eq!(192u32.aligned_delta(64, 64), Some(128));
+    /// assert_eq!(192u32.aligned_delta(65, 64), None);
+    /// assert_eq!(64u32.aligned_delta(192, 64), None);
+    /// ```
+    fn aligned_delta(self, other: Self, align: Self) -> Option<Self>;
+}
+
+macro_rules! impl_aligned_delta {
+    ($Type:ty) => {
+        impl AlignedDelta for $Type {
+            fn aligned_delta(self, other: Self, align: Self) -> Option<Self> {
+                if align == 0 || self % align != 0 || other % align != 0 || self < other {
+                    return None;
+                }
+                self.checked_sub(other)
+            }
+        }
+    };
+}
+
+impl_aligned_delta!(i8);
+impl_aligned_delta!(i16);
+impl_aligned_delta!(i32);
+impl_aligned_delta!(i64);
+impl_aligned_delta!(isize);
+impl_aligned_delta!(u8);
+impl_aligned_delta!(u16);
+impl_aligned_delta!(u32);
+impl_aligned_delta!(u64);
+impl_aligned_delta!(usize);
+
+/// 定义按有理数比例缩放整数的契定。
+pub trait ScaleByRatio {
+    /// 返回四舍五入后的 `self * num / den`。
+    ///
+    /// 中间结果使用 `u128` 计算，不会在除法前溢出；`den` 为 `0` 或结果超出类型范围时
+    /// 返回 `Self::MAX`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{ScaleByRatio};
+    ///
+    /// assert_eq!(1_000_000u64.scale_by_ratio(1001, 1000), 1_001_000);
+    /// assert_eq!(10u32.scale_by_ratio(1, 4), 3);
+    /// assert_eq!(10u32.scale_by_ratio(1, 0), u32::MAX);
+    /// ```
+    fn scale_by_ratio(self, num: Self, den: Self) -> Self;
+}
+
+macro_rules! impl_scale_by_ratio {
+    ($Type:ty) => {
+        impl ScaleByRatio for $Type {
+            fn scale_by_ratio(self, num: Self, den: Self) -> Self {
+                if den == 0 {
+                    return Self::MAX;
+                }
+                let den = den as u128;
+                let r = (self as u128 * num as u128 + den / 2) / den;
+                if r > Self::MAX as u128 {
+                    Self::MAX
+                } else {
+                    r as Self
+                }
+            }
+        }
+    };
+}
+
+impl_scale_by_ratio!(u8);
+impl_scale_by_ratio!(u16);
+impl_scale_by_ratio!(u32);
+impl_scale_by_ratio!(u64);
+impl_scale_by_ratio!(usize);
+
+/// 返回能整除 `sizes` 中所有大小的最大 2 的幂。
+///
+/// 值为 `0` 的大小可被任意对齐整除，因此会被忽略；数组为空或全为 `0` 时返回 `1`。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{common_alignment};
+///
+/// assert_eq!(common_alignment(&[64, 96, 128]), 32);
+/// assert_eq!(common_alignment(&[4096, 0]), 4096);
+/// assert_eq!(common_alignment(&[]), 1);
+/// ```
+pub fn common_alignment(sizes: &[usize]) -> usize {
+    let bits = sizes.iter().fold(0usize, |acc, &s| acc | s);
+    if bits == 0 {
+        1
+    } else {
+        bits & bits.wrapping_neg()
+    }
+}
+
+/// 缓动曲线的种类。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EaseKind {
+    /// 线性。
+    Linear,
+    /// 二次缓入。
+    QuadIn,
+    /// 二次缓出。
+    QuadOut,
+    /// 二次缓入缓出。
+    QuadInOut,
+    /// 三次缓入缓出。
+    CubicInOut,
+}
+
+/// 定义缓动曲线变换的契定。
+pub trait Ease {
+    /// 将 `[0, 1]` 范围内的进度值按 `kind` 指定的缓动曲线进行变换。
+    ///
+    /// 输入会先被限制在 `[0, 1]` 之内，所有曲线均满足 `0 -> 0` 及 `1 -> 1`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Ease, EaseKind};
+    ///
+    /// assert_eq!(0.5f32.ease(EaseKind::QuadIn), 0.25);
+    /// assert_eq!(0.5f32.ease(EaseKind::QuadOut), 0.75);
+    /// assert_eq!(2.0f32.ease(EaseKind::CubicInOut), 1.0);
+    /// ```
+    fn ease(self, kind: EaseKind) -> Self;
+}
+
+macro_rules! impl_ease {
+    ($Type:ty) => {
+        impl Ease for $Type {
+            fn ease(self, kind: EaseKind) -> Self {
+                let t = self.clamped(0.0, 1.0);
+                match kind {
+                    EaseKind::Linear => t,
+                    EaseKind::QuadIn => t * t,
+                    EaseKind::QuadOut => t * (2.0 - t),
+                    EaseKind::QuadInOut => {
+                        if t < 0.5 {
+                            2.0 * t * t
+                        } else {
+                            1.0 - 2.0 * (1.0 - t) * (1.0 - t)
+                        }
+                    }
+                    EaseKind::CubicInOut => {
+                        if t < 0.5 {
+                            4.0 * t * t * t
+                        } else {
+                            1.0 - 4.0 * (1.0 - t) * (1.0 - t) * (1.0 - t)
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_ease!(f32);
+impl_ease!(f64);
+
+/// 带指数衰减的峰值表。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{PeakMeter};
+///
+/// let mut meter = PeakMeter::new(0.5);
+/// assert_eq!(meter.update(0.8), 0.8);
+/// assert_eq!(meter.update(0.1), 0.4);
+/// assert_eq!(meter.update(-0.9), 0.9);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PeakMeter {
+    decay: f32,
+    value: f32,
+}
+
+impl PeakMeter {
+    /// 创建一个峰值表，`decay` 为每个样本的衰减系数，会被限制在 `[0, 1]` 的范围之内。
+    pub fn new(decay: f32) -> Self {
+        Self {
+            decay: decay.clamped(0.0, 1.0),
+            value: 0.0,
+        }
+    }
+
+    /// 输入一个样本并返回当前读数。
+    ///
+    /// 样本的绝对值高于衰减后的读数时读数立即升至该值，否则按衰减系数下降。
+    pub fn update(&mut self, sample: f32) -> f32 {
+        self.value = sample.abs().max(self.value * self.decay);
+        self.value
+    }
+
+    /// 返回当前读数。
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+}
+
+/// 指数加权的滑动均值与方差。
+///
+/// 无需保存历史样本即可跟踪数据流的均值与方差，可用于检测偏离均值若干倍标准差的异常样本。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{EwmaVariance};
+///
+/// let mut ewma = EwmaVariance::new(0.5);
+/// assert_eq!(ewma.update(2.0), (2.0, 0.0));
+/// assert_eq!(ewma.update(4.0), (3.0, 1.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EwmaVariance {
+    alpha: f64,
+    mean: f64,
+    variance: f64,
+    primed: bool,
+}
+
+impl EwmaVariance {
+    /// 创建一个滑动方差，`alpha` 为新样本的权重，会被限制在 `[0, 1]` 的范围之内。
+    ///
+    /// `alpha` 越大对新样本的响应越快，越小则越平滑。
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            alpha: alpha.clamped(0.0, 1.0),
+            ..Default::default()
+        }
+    }
+
+    /// 输入一个样本并返回更新后的 `(均值, 方差)`。
+    ///
+    /// 首个样本直接作为均值，方差为 `0`。
+    pub fn update(&mut self, x: f64) -> (f64, f64) {
+        if self.primed {
+            let diff = x - self.mean;
+            let incr = self.alpha * diff;
+            self.mean += incr;
+            self.variance = (1.0 - self.alpha) * (self.variance + diff * incr);
+        } else {
+            self.mean = x;
+            self.variance = 0.0;
+            self.primed = true;
+        }
+        (self.mean, self.variance)
+    }
+
+    /// 返回当前均值。
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// 返回当前方差。
+    pub fn variance(&self) -> f64 {
+        self.variance
+    }
+}
+
+/// 带迟滞的阈值开关。
+///
+/// 数值高于上限阈值时打开，低于下限阈值时关闭，处于两者之间时保持原状态，
+/// 可避免数值在单一阈值附近抖动时开关频繁切换。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{Hysteresis};
+///
+/// let mut gate = Hysteresis::new(0.2, 0.8);
+/// assert!(!gate.update(0.5));
+/// assert!(gate.update(0.9));
+/// assert!(gate.update(0.5));
+/// assert!(!gate.update(0.1));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hysteresis {
+    low: f64,
+    high: f64,
+    on: bool,
+}
+
+impl Hysteresis {
+    /// 创建一个初始为关闭状态的迟滞开关，`low` 与 `high` 分别为关闭与打开的阈值。
+    ///
+    /// # Panics
+    ///
+    /// `low` 不小于 `high` 或任一阈值为 NaN 时 panic。
+    pub fn new(low: f64, high: f64) -> Self {
+        assert!(
+            low < high,
+            "Hysteresis: low threshold must be less than high"
+        );
+        Self {
+            low,
+            high,
+            on: false,
+        }
+    }
+
+    /// 输入一个数值并返回更新后的开关状态。
+    pub fn update(&mut self, value: f64) -> bool {
+        if value > self.high {
+            self.on = true;
+        } else if value < self.low {
+            self.on = false;
+        }
+        self.on
+    }
+
+    /// 返回当前开关状态。
+    pub fn is_on(&self) -> bool {
+        self.on
+    }
+}
+
+/// 定义偏置表示与相对值之间转换的契定。
+///
+/// 有符号类型使用饱和运算；无符号类型使用回绕运算，结果按 `as` 转换为同宽度的
+/// 有符号类型即为相对值，从而低于偏置的值也能正确得到负数。
+pub trait Biased {
+    /// 返回当前值相对于偏置 `bias` 的值，即 `self - bias`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Biased};
+    ///
+    /// // 无符号 8 位 PCM 以 128 为零点。
+    /// assert_eq!(200u8.to_biased(128) as i8, 72);
+    /// assert_eq!(50u8.to_biased(128) as i8, -78);
+    /// assert_eq!(i8::MIN.to_biased(1), i8::MIN);
+    /// ```
+    fn to_biased(self, bias: Self) -> Self;
+
+    /// 将相对于偏置 `bias` 的值还原，即 `self + bias`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Biased};
+    ///
+    /// assert_eq!(72u8.from_biased(128), 200);
+    /// assert_eq!((-78i8 as u8).from_biased(128), 50);
+    /// assert_eq!(i8::MAX.from_biased(1), i8::MAX);
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn from_biased(self, bias: Self) -> Self;
+}
+
+macro_rules! impl_biased {
+    ($Type:ty, $sub:ident, $add:ident) => {
+        impl Biased for $Type {
+            fn to_biased(self, bias: Self) -> Self {
+                self.$sub(bias)
+            }
+
+            fn from_biased(self, bias: Self) -> Self {
+                self.$add(bias)
+            }
+        }
+    };
+}
+
+impl_biased!(i8, saturating_sub, saturating_add);
+impl_biased!(i16, saturating_sub, saturating_add);
+impl_biased!(i32, saturating_sub, saturating_add);
+impl_biased!(i64, saturating_sub, saturating_add);
+impl_biased!(isize, saturating_sub, saturating_add);
+impl_biased!(u8, wrapping_sub, wrapping_add);
+impl_biased!(u16, wrapping_sub, wrapping_add);
+impl_biased!(u32, wrapping_sub, wrapping_add);
+impl_biased!(u64, wrapping_sub, wrapping_add);
+impl_biased!(usize, wrapping_sub, wrapping_add);
+
+/// 返回 `count` 个大小为 `elem_size` 的元素之后，为使总大小对齐到 `align` 所需的填充字节数。
+///
+/// 总大小计算溢出或 `align` 为 `0` 时返回 `None`。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{array_padding};
+///
+/// assert_eq!(array_padding(12, 5, 64), Some(4));
+/// assert_eq!(array_padding(16, 4, 64), Some(0));
+/// assert_eq!(array_padding(usize::MAX, 2, 64), None);
+/// ```
+pub fn array_padding(elem_size: usize, count: usize, align: usize) -> Option<usize> {
+    if align == 0 {
+        return None;
+    }
+    let total = elem_size.checked_mul(count)?;
+    Some((align - total % align) % align)
+}
+
+/// 定义按分段线性曲线映射数值的契定。
+pub trait PiecewiseMap: Sized {
+    /// 按已按输入升序排列的 `(输入, 输出)` 断点对当前值进行分段线性插值。
+    ///
+    /// 低于首个断点或高于末个断点时分别取首末断点的输出；`points` 为空时原样返回。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{PiecewiseMap};
+    ///
+    /// let curve = [(0.0f32, 0.0), (1.0, 10.0), (2.0, 40.0)];
+    /// assert_eq!(0.5f32.piecewise_map(&curve), 5.0);
+    /// assert_eq!(1.5f32.piecewise_map(&curve), 25.0);
+    /// assert_eq!(3.0f32.piecewise_map(&curve), 40.0);
+    /// ```
+    fn piecewise_map(self, points: &[(Self, Self)]) -> Self;
+}
+
+macro_rules! impl_piecewise_map {
+    ($Type:ty) => {
+        impl PiecewiseMap for $Type {
+            fn piecewise_map(self, points: &[(Self, Self)]) -> Self {
+                let (first, last) = match (points.first(), points.last()) {
+                    (Some(&first), Some(&last)) => (first, last),
+                    _ => return self,
+                };
+                if self <= first.0 {
+                    return first.1;
+                }
+                if self >= last.0 {
+                    return last.1;
+                }
+                for w in points.windows(2) {
+                    let ((x0, y0), (x1, y1)) = (w[0], w[1]);
+                    if self <= x1 {
+                        if x1 <= x0 {
+                            return y1;
+                        }
+                        return y0 + (self - x0) * (y1 - y0) / (x1 - x0);
+                    }
+                }
+                last.1
+            }
+        }
+    };
+}
+
+impl_piecewise_map!(f32);
+impl_piecewise_map!(f64);
+
+/// 定义带上限报告的饱和加法契定。
+pub trait SaturatingAddReported: Sized {
+    /// 返回限制在 `max` 以内的 `self + rhs`，以及和是否超出了 `max`（含类型溢出）。
+    ///
+    /// 和恰好等于 `max` 时不视为超出。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{SaturatingAddReported};
+    ///
+    /// assert_eq!(90u32.saturating_add_reported(5, 99), (95, false));
+    /// assert_eq!(95u32.saturating_add_reported(10, 99), (99, true));
+    /// ```
+    fn saturating_add_reported(self, rhs: Self, max: Self) -> (Self, bool);
+}
+
+macro_rules! impl_saturating_add_reported {
+    ($Type:ty) => {
+        impl SaturatingAddReported for $Type {
+            fn saturating_add_reported(self, rhs: Self, max: Self) -> (Self, bool) {
+                match self.checked_add(rhs) {
+                    Some(sum) if sum <= max => (sum, false),
+                    _ => (max, true),
+                }
+            }
+        }
+    };
+}
+
+impl_saturating_add_reported!(u8);
+impl_saturating_add_reported!(u16);
+impl_saturating_add_reported!(u32);
+impl_saturating_add_reported!(u64);
+impl_saturating_add_reported!(usize);
+
+/// 定义将归一化浮点数量化为离散等级的契定。
+pub trait Quantize {
+    /// 将 `[0, 1]` 范围内的值四舍五入量化为 `0..levels` 之间的等级。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Quantize};
+    ///
+    /// assert_eq!(0.5f32.quantize(256), 128);
+    /// assert_eq!(1.5f32.quantize(256), 255);
+    /// ```
+    fn quantize(self, levels: u32) -> u32
+    where
+        Self: Sized,
+    {
+        self.quantize_dithered(levels, 0.0)
+    }
+
+    /// 在量化前叠加以量化步长为单位的抖动噪声 `noise`，以减轻色带。
+    ///
+    /// 噪声由调用者提供（如取自三角分布 `[-1, 1]` 的随机数），因此结果是确定的。
+    /// 结果被限制在 `0..levels` 之内，`levels` 为 `0` 时返回 `0`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Quantize};
+    ///
+    /// assert_eq!(0.5f32.quantize_dithered(256, 0.0), 0.5f32.quantize(256));
+    /// assert_eq!(0.5f32.quantize_dithered(256, -0.75), 127);
+    /// ```
+    fn quantize_dithered(self, levels: u32, noise: f32) -> u32;
+}
+
+macro_rules! impl_quantize {
+    ($Type:ty) => {
+        impl Quantize for $Type {
+            fn quantize_dithered(self, levels: u32, noise: f32) -> u32 {
+                if levels == 0 {
+                    return 0;
+                }
+                let max = (levels - 1) as $Type;
+                let v = (self * max + noise as $Type).round();
+                v.clamped(0.0, max) as u32
+            }
+        }
+    };
+}
+
+impl_quantize!(f32);
+impl_quantize!(f64);
+
+/// 定义以二分法查找满足条件的最大值的契定。
+pub trait BisectMax: Sized {
+    /// 返回 `[lo, hi]` 中使 `f` 为 `true` 的最大值，要求 `f` 单调（先真后假）。
+    ///
+    /// `f(lo)` 为 `false` 或 `lo > hi` 时返回 `None`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{BisectMax};
+    ///
+    /// assert_eq!(u32::bisect_max(0, 100, |n| n * n <= 100), Some(10));
+    /// assert_eq!(u32::bisect_max(11, 100, |n| n * n <= 100), None);
+    /// ```
+    fn bisect_max<F: Fn(Self) -> bool>(lo: Self, hi: Self, f: F) -> Option<Self>;
+}
+
+macro_rules! impl_bisect_max {
+    ($Type:ty) => {
+        impl BisectMax for $Type {
+            fn bisect_max<F: Fn(Self) -> bool>(lo: Self, hi: Self, f: F) -> Option<Self> {
+                if lo > hi || !f(lo) {
+                    return None;
+                }
+                let (mut lo, mut hi) = (lo, hi);
+                while lo < hi {
+                    let mid = ((lo as i128 + hi as i128 + 1) / 2) as Self;
+                    if f(mid) {
+                        lo = mid;
+                    } else {
+                        hi = mid - 1;
+                    }
+                }
+                Some(lo)
+            }
+        }
+    };
+}
+
+impl_bisect_max!(i8);
+impl_bisect_max!(i16);
+impl_bisect_max!(i32);
+impl_bisect_max!(i64);
+impl_bisect_max!(isize);
+impl_bisect_max!(u8);
+impl_bisect_max!(u16);
+impl_bisect_max!(u32);
+impl_bisect_max!(u64);
+impl_bisect_max!(usize);
+
+/// 返回将 `[start, start + len)` 区间的起点向下、终点向上对齐到 `align` 后的 `(起点, 长度)`。
+///
+/// 区间终点或对齐后的终点超出 `usize` 范围，或 `align` 为 `0` 时返回 `None`。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{align_region};
+///
+/// assert_eq!(align_region(100, 50, 64), Some((64, 128)));
+/// assert_eq!(align_region(128, 64, 64), Some((128, 64)));
+/// assert_eq!(align_region(usize::MAX - 10, 5, 64), None);
+/// ```
+pub fn align_region(start: usize, len: usize, align: usize) -> Option<(usize, usize)> {
+    if align == 0 {
+        return None;
+    }
+    let end = start.checked_add(len)?.checked_align_upwards(align)?;
+    let start = start.align_downwards(align);
+    Some((start, end - start))
+}
+
+/// 定义将整数拆分为指定进制各位数字的契定。
+#[cfg(feature = "alloc")]
+pub trait ToDigits: Sized {
+    /// 返回数值在 `base` 进制下由高位至低位排列的各位数字。
+    ///
+    /// 数值为 `0` 时返回 `[0]`，`base` 小于 `2` 时返回空序列。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{ToDigits};
+    ///
+    /// assert_eq!(255u32.to_digits(16), [15, 15]);
+    /// assert_eq!(255u32.to_digits(10), [2, 5, 5]);
+    /// assert_eq!(0u8.to_digits(10), [0]);
+    /// assert!(7u8.to_digits(1).is_empty());
+    /// ```
+    fn to_digits(self, base: Self) -> Vec<Self>;
+}
+
+macro_rules! impl_to_digits {
+    ($Type:ty) => {
+        #[cfg(feature = "alloc")]
+        impl ToDigits for $Type {
+            fn to_digits(self, base: Self) -> Vec<Self> {
+                if base < 2 {
+                    return Vec::new();
+                }
+                let mut digits = Vec::new();
+                let mut v = self;
+                loop {
+                    digits.push(v % base);
+                    v /= base;
+                    if v == 0 {
+                        break;
+                    }
+                }
+                digits.reverse();
+                digits
+            }
+        }
+    };
+}
+
+impl_to_digits!(u8);
+impl_to_digits!(u16);
+impl_to_digits!(u32);
+impl_to_digits!(u64);
+impl_to_digits!(usize);
+
+/// 返回不小于 `requested` 的最小 2 的幂，用作可按位掩码索引的环形缓冲区容量。
+///
+/// `requested` 为 `0` 时返回 `1`；结果超出 `usize` 范围时返回可表示的最大 2 的幂。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{ring_capacity};
+///
+/// assert_eq!(ring_capacity(1000), 1024);
+/// assert_eq!(ring_capacity(1024), 1024);
+/// assert_eq!(ring_capacity(0), 1);
+/// ```
+pub fn ring_capacity(requested: usize) -> usize {
+    requested.next_pow2().unwrap_or(1 << (usize::BITS - 1))
+}
+
+/// 定义生成等间距序列的契定。
+#[cfg(feature = "alloc")]
+pub trait Linspace: Sized {
+    /// 返回从 `start` 到 `end`（含两端）的 `n` 个等间距数值。
+    ///
+    /// 每个值均由首项直接计算而非逐项累加，末项精确等于 `end`；
+    /// `n` 为 `0` 时返回空序列，为 `1` 时仅返回 `start`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Linspace};
+    ///
+    /// assert_eq!(f64::linspace(0.0, 1.0, 5), [0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// assert_eq!(f32::linspace(2.0, 3.0, 1), [2.0]);
+    /// ```
+    fn linspace(start: Self, end: Self, n: usize) -> Vec<Self>;
+}
+
+macro_rules! impl_linspace {
+    ($Type:ty) => {
+        #[cfg(feature = "alloc")]
+        impl Linspace for $Type {
+            fn linspace(start: Self, end: Self, n: usize) -> Vec<Self> {
+                match n {
+                    0 => Vec::new(),
+                    1 => alloc::vec![start],
+                    _ => {
+                        let span = end - start;
+                        let last = (n - 1) as Self;
+                        let mut out: Vec<Self> = (0..n - 1)
+                            .map(|i| start + span * (i as Self / last))
+                            .collect();
+                        out.push(end);
+                        out
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_linspace!(f32);
+impl_linspace!(f64);
+
+/// 定义生成指数（等比）序列的契定。
+///
+/// 需要启用 `std` 特性。
+#[cfg(feature = "std")]
+pub trait ExpRamp: Sized {
+    /// 返回从 `start` 到 `end`（含两端）按等比级数变化的 `n` 个数值，相邻元素之比恒定。
+    ///
+    /// 用于幅值渐变时听感比线性渐变更平滑；末项精确等于 `end`，
+    /// `n` 为 `0` 时返回空序列，为 `1` 时仅返回 `start`。
+    ///
+    /// 等比级数要求两端同为正数，任一端不大于 `0` 时退化为 [Linspace] 的线性序列。
+    ///
+    /// [Linspace]: trait.Linspace.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{ExpRamp};
+    ///
+    /// assert_eq!(f64::exp_ramp(1.0, 100.0, 3), [1.0, 10.0, 100.0]);
+    /// assert_eq!(f32::exp_ramp(0.0, 1.0, 3), [0.0, 0.5, 1.0]);
+    /// ```
+    fn exp_ramp(start: Self, end: Self, n: usize) -> Vec<Self>;
+}
+
+macro_rules! impl_exp_ramp {
+    ($Type:ty) => {
+        #[cfg(feature = "std")]
+        impl ExpRamp for $Type {
+            fn exp_ramp(start: Self, end: Self, n: usize) -> Vec<Self> {
+                if start <= 0.0 || end <= 0.0 || start.is_nan() || end.is_nan() {
+                    return Self::linspace(start, end, n);
+                }
+                match n {
+                    0 => Vec::new(),
+                    1 => alloc::vec![start],
+                    _ => {
+                        let ratio = end / start;
+                        let last = (n - 1) as Self;
+                        let mut out: Vec<Self> = (0..n - 1)
+                            .map(|i| start * ratio.powf(i as Self / last))
+                            .collect();
+                        out.push(end);
+                        out
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_exp_ramp!(f32);
+impl_exp_ramp!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_approach() {
+        assert!(1000u32.is_approach(1000, 0.1));
+        assert!(!900u32.is_approach(1000, 0.099));
+        assert!(900u32.is_approach(1000, 0.1));
+        assert!(900u32.is_approach(1000, 0.2));
+        assert!(800u32.is_approach(1000, 0.2));
+        assert!(!700u32.is_approach(1000, 0.2));
+        assert!(!600u32.is_approach(1000, 0.2));
+        assert!(!0u32.is_approach(1000, 0.2));
+        let a = u32::MAX - u32::MAX / 1000 * 99;
+        assert!(a.is_approach(u32::MAX, 0.2));
+        assert_eq!(8i32.is_approach(10, 0.2), 10i32.is_approach(8, 0.2));
+        assert!(!10i32.is_approach(8, 0.15));
+        assert!((-90i64).is_approach(-100, 0.1));
+        assert!(!(-90i64).is_approach(90, 0.5));
+        assert!(0u8.is_approach(0, 0.0));
+        assert!(i64::MIN.is_approach(i64::MIN + 1, 0.01));
+    }
+
+    #[test]
+    fn test_is_approach_float() {
+        assert!(0.75f32.is_approach(1.0, 0.25));
+        assert!(1.0f32.is_approach(0.75, 0.25));
+        assert!(!0.9f64.is_approach(1.0, 0.05));
+        assert!(0.0f64.is_approach(0.0, 0.0));
+        assert!(0.0f32.is_approach(-0.0, 0.1));
+        assert!(!0.0f64.is_approach(1e-9, 0.1));
+        assert!((-7.5f64).is_approach(-10.0, 0.25));
+        assert!(!(-10.0f64).is_approach(10.0, 0.5));
+        assert!(!f32::NAN.is_approach(1.0, 1.0));
+        assert!(!1.0f64.is_approach(f64::NAN, 1.0));
+        assert!(!f64::NAN.is_approach(f64::NAN, 1.0));
+    }
+
+    #[test]
+    fn test_is_in_range() {
+        for a in 0..1000000 {
+            assert!(!a.is_in_range(a - 2, a - 1));
+            assert!(a.is_in_range(a - 1, a));
+            assert!(a.is_in_range(a, a));
+            assert!(a.is_in_range(a, a + 1));
+        }
+    }
+
+    #[test]
+    fn test_blend() {
+        assert_eq!(0u8.blend(255, 0.5), 128);
+        assert_eq!(0u8.blend(255, 0.0), 0);
+        assert_eq!(0u8.blend(255, 1.0), 255);
+        assert_eq!(0u8.blend(255, -1.0), 0);
+        assert_eq!(0u8.blend(255, 2.0), 255);
+        assert_eq!((-100i32).blend(100, 0.75), 50);
+        assert_eq!(2.0f64.blend(4.0, 0.5), 3.0);
+        assert_eq!(200u8.blend(100, f32::NAN), 200);
+        assert_eq!((-7i64).blend(7, f32::NAN), -7);
+        assert_eq!(2.0f64.blend(4.0, f32::NAN), 2.0);
+        assert_eq!(1.5f32.blend(-3.0, f32::NAN), 1.5);
+        assert_eq!(i64::MAX.blend(0, 0.0), i64::MAX);
+    }
+
+    #[test]
+    fn test_aligned_steps() {
+        let v: Vec<usize> = aligned_steps(0, 128, 32, 16).collect();
+        assert_eq!(v, [0, 32, 64, 96]);
+        let v: Vec<usize> = aligned_steps(1, 129, 20, 16).collect();
+        assert_eq!(v, [16, 48, 80, 112]);
+        let v: Vec<usize> = aligned_steps(120, 128, 32, 16).collect();
+        assert!(v.is_empty());
+        let v: Vec<usize> = aligned_steps(0, 64, 0, 16).collect();
+        assert_eq!(v, [0]);
+        let v: Vec<usize> = aligned_steps(usize::MAX - 15, usize::MAX, 32, 16).collect();
+        assert_eq!(v, [usize::MAX - 15]);
+    }
+
+    #[test]
+    fn test_hamming_distance() {
+        assert_eq!(0b1010u8.hamming_distance(0b0110), 2);
+        assert_eq!(0u16.hamming_distance(0), 0);
+        assert_eq!(u64::MAX.hamming_distance(0), 64);
+        assert_eq!(0xF0F0usize.hamming_distance(0x0F0F), 16);
+    }
+
+    #[test]
+    fn test_snap_to_grid() {
+        assert_eq!(7.0f32.snap_to_grid(5.0, 1.0), 6.0);
+        assert_eq!((-3.0f32).snap_to_grid(5.0, 1.0), -4.0);
+        assert_eq!(2.4f64.snap_to_grid(0.5, 0.0), 2.5);
+        assert_eq!(7.0f64.snap_to_grid(-5.0, 1.0), 7.0);
+        assert_eq!(7.0f64.snap_to_grid(f64::NAN, 1.0), 7.0);
+    }
+
+    #[test]
+    fn test_reciprocal_q() {
+        let r = 4u32.reciprocal_q(16);
+        for x in 0..10000u32 {
+            assert_eq!((x * r) >> 16, x / 4);
+        }
+        assert_eq!(3u64.reciprocal_q(16), 21845);
+        assert_eq!(1u8.reciprocal_q(8), u8::MAX);
+        assert_eq!(2u8.reciprocal_q(8), 128);
+        assert_eq!(0u16.reciprocal_q(8), u16::MAX);
+        assert_eq!(1u64.reciprocal_q(128), u64::MAX);
+    }
+
+    #[test]
+    fn test_reduced_ratio() {
+        assert_eq!(1920u32.as_reduced_ratio(1080), (16, 9));
+        assert_eq!(1280u16.as_reduced_ratio(1024), (5, 4));
+        assert_eq!(7u8.as_reduced_ratio(7), (1, 1));
+        assert_eq!(13u64.as_reduced_ratio(7), (13, 7));
+        assert_eq!(5usize.as_reduced_ratio(0), (1, 0));
+        assert_eq!(0u32.as_reduced_ratio(0), (0, 0));
+    }
+
+    #[test]
+    fn test_frame_count() {
+        assert_eq!(2.0.to_frames(30.0), 60);
+        assert_eq!(0.52.to_frames(25.0), 13);
+        assert_eq!((-1.0).to_frames(30.0), 0);
+        assert_eq!(f64::NAN.to_frames(30.0), 0);
+        assert_eq!(1.0.to_frames(f64::NAN), 0);
+        assert_eq!(f64::INFINITY.to_frames(30.0), u64::MAX);
+        assert_eq!(f64::from_frames(60, 30.0), 2.0);
+        assert_eq!(f64::from_frames(60, 0.0), 0.0);
+        assert_eq!(f64::from_frames(12345.0.to_frames(24.0), 24.0), 12345.0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_gamma() {
+        for i in 0..=100 {
+            let v = i as f64 / 100.0;
+            assert!((v.apply_gamma(2.2).remove_gamma(2.2) - v).abs() < 1e-12);
+            let v = v as f32;
+            assert!((v.remove_gamma(2.2).apply_gamma(2.2) - v).abs() < 1e-5);
+        }
+        assert_eq!((-1.0f64).apply_gamma(2.2), 0.0);
+        assert_eq!(0.5f32.apply_gamma(0.0), 0.5);
+        assert_eq!(0.5f64.remove_gamma(-1.0), 0.5);
+    }
+
+    #[test]
+    fn test_page_count() {
+        assert_eq!(4097usize.page_count(4096), 2);
+        assert_eq!(1usize.page_count(4096), 1);
+        assert_eq!(8192usize.page_count(4096), 2);
+        assert_eq!(100usize.page_count(0), 0);
+        assert_eq!(usize::MAX.page_count(4096), usize::MAX / 4096 + 1);
+        for n in 1..10000usize {
+            assert_eq!(n.page_count(64), n.align_upwards(64) / 64);
+        }
+    }
+
+    #[test]
+    fn test_mul_add_clamped() {
+        assert_eq!(3i16.mul_add_clamped(4, 5, -100, 100), 17);
+        assert_eq!(30000i16.mul_add_clamped(30000, 0, -1000, 1000), 1000);
+        assert_eq!((-30000i16).mul_add_clamped(30000, 0, -1000, 1000), -1000);
+        assert_eq!(200u8.mul_add_clamped(200, 200, 0, 250), 250);
+        assert_eq!(
+            u64::MAX.mul_add_clamped(u64::MAX, u64::MAX, 0, u64::MAX),
+            u64::MAX
+        );
+        assert_eq!(
+            i64::MIN.mul_add_clamped(i64::MAX, -1, i64::MIN, 0),
+            i64::MIN
+        );
+        assert_eq!(1u32.mul_add_clamped(1, 1, 5, 10), 5);
+    }
+
+    #[test]
+    fn test_reduce_depth() {
+        assert_eq!(255u8.reduce_depth(8, 5), 31);
+        assert_eq!(0u8.reduce_depth(8, 5), 0);
+        assert_eq!(31u8.reduce_depth(5, 8), 255);
+        for v in 0..=255u8 {
+            let back = v.reduce_depth(8, 5).reduce_depth(5, 8);
+            assert!((back as i32 - v as i32).abs() <= 4);
+        }
+        assert_eq!(1023u16.reduce_depth(10, 8), 255);
+        assert_eq!(2000u16.reduce_depth(10, 8), 255);
+        assert_eq!(255u8.reduce_depth(8, 16), 255);
+        assert_eq!(255u32.reduce_depth(8, 16), 65535);
+        assert_eq!(u64::MAX.reduce_depth(64, 1), 1);
+        assert_eq!(7u8.reduce_depth(0, 8), 0);
+    }
+
+    #[test]
+    fn test_clamp_elastic() {
+        let near = 101.0f64.clamp_elastic(0.0, 100.0, 0.1);
+        assert!(near > 100.9 && near < 101.0);
+        let far = 1e6f64.clamp_elastic(0.0, 100.0, 0.1);
+        assert!(far > 109.9 && far < 110.0);
+        let below = (-1e6f64).clamp_elastic(0.0, 100.0, 0.1);
+        assert!(below < -9.9 && below > -10.0);
+        assert_eq!(0.0f32.clamp_elastic(0.0, 100.0, 0.1), 0.0);
+        assert_eq!(100.0f32.clamp_elastic(0.0, 100.0, 0.1), 100.0);
+        assert_eq!(150.0f32.clamp_elastic(0.0, 100.0, 0.0), 150.0);
+    }
+
+    #[test]
+    fn test_morton() {
+        assert_eq!(morton_encode(0, 0), 0);
+        assert_eq!(morton_encode(1, 0), 1);
+        assert_eq!(morton_encode(0, 1), 2);
+        assert_eq!(morton_encode(5, 9), 0b1001_0011);
+        assert_eq!(morton_encode(u32::MAX, u32::MAX), u64::MAX);
+        assert_eq!(morton_encode(u32::MAX, 0), 0x5555_5555_5555_5555);
+        for &(x, y) in &[
+            (0, 0),
+            (1, 2),
+            (1920, 1080),
+            (u32::MAX, 0),
+            (0xDEAD_BEEF, 0x1234_5678),
+        ] {
+            assert_eq!(morton_decode(morton_encode(x, y)), (x, y));
+        }
+    }
+
+    #[test]
+    fn test_aligned_delta() {
+        assert_eq!(4096usize.aligned_delta(1024, 64), Some(3072));
+        assert_eq!(64usize.aligned_delta(64, 64), Some(0));
+        assert_eq!(4097usize.aligned_delta(1024, 64), None);
+        assert_eq!(4096usize.aligned_delta(1023, 64), None);
+        assert_eq!(1024usize.aligned_delta(4096, 64), None);
+        assert_eq!(4096usize.aligned_delta(1024, 0), None);
+        assert_eq!(64i32.aligned_delta(-64, 64), Some(128));
+        assert_eq!(64i8.aligned_delta(-128, 64), None);
+    }
+
+    #[test]
+    fn test_scale_by_ratio() {
+        assert_eq!(1_000_000u64.scale_by_ratio(1001, 1000), 1_001_000);
+        assert_eq!(u64::MAX.scale_by_ratio(1000, 1001), 18428315757951600015);
+        assert_eq!(u64::MAX.scale_by_ratio(u64::MAX, u64::MAX), u64::MAX);
+        assert_eq!(u64::MAX.scale_by_ratio(2, 1), u64::MAX);
+        assert_eq!(200u8.scale_by_ratio(200, 250), 160);
+        assert_eq!(5u16.scale_by_ratio(1, 2), 3);
+        assert_eq!(5u16.scale_by_ratio(1, 0), u16::MAX);
+    }
+
+    #[test]
+    fn test_common_alignment() {
+        assert_eq!(common_alignment(&[64, 96, 128]), 32);
+        assert_eq!(common_alignment(&[64]), 64);
+        assert_eq!(common_alignment(&[3, 64]), 1);
+        assert_eq!(common_alignment(&[0, 0]), 1);
+        assert_eq!(common_alignment(&[]), 1);
+        let top = 1usize << (usize::BITS - 1);
+        assert_eq!(common_alignment(&[top]), top);
+        for &s in &[48usize, 80, 112] {
+            assert_eq!(s % common_alignment(&[48, 80, 112]), 0);
+        }
+    }
+
+    #[test]
+    fn test_ease() {
+        let kinds = [
+            EaseKind::Linear,
+            EaseKind::QuadIn,
+            EaseKind::QuadOut,
+            EaseKind::QuadInOut,
+            EaseKind::CubicInOut,
+        ];
+        for &kind in &kinds {
+            assert_eq!(0.0f64.ease(kind), 0.0);
+            assert_eq!(1.0f64.ease(kind), 1.0);
+            assert_eq!((-1.0f32).ease(kind), 0.0);
+            assert_eq!(2.0f32.ease(kind), 1.0);
+            for i in 0..100 {
+                let (a, b) = (i as f64 / 100.0, (i + 1) as f64 / 100.0);
+                assert!(a.ease(kind) <= b.ease(kind));
+            }
+        }
+        assert_eq!(0.5f32.ease(EaseKind::QuadIn), 0.25);
+        assert_eq!(0.5f64.ease(EaseKind::QuadInOut), 0.5);
+        assert_eq!(0.25f64.ease(EaseKind::CubicInOut), 0.0625);
+    }
+
+    #[test]
+    fn test_peak_meter() {
+        let mut meter = PeakMeter::new(0.9);
+        assert_eq!(meter.value(), 0.0);
+        assert_eq!(meter.update(1.0), 1.0);
+        let mut last = meter.value();
+        for _ in 0..10 {
+            let v = meter.update(0.0);
+            assert!(v < last);
+            assert!((v - last * 0.9).abs() < 1e-6);
+            last = v;
+        }
+        assert!((meter.update(0.05) - 0.9f32.powi(11)).abs() < 1e-6);
+        assert_eq!(meter.update(-2.0), 2.0);
+        assert_eq!(PeakMeter::new(5.0).update(1.0), 1.0);
+        let mut hold = PeakMeter::new(1.0);
+        hold.update(0.5);
+        assert_eq!(hold.update(0.0), 0.5);
+    }
+
+    #[test]
+    fn test_biased() {
+        assert_eq!(200u8.to_biased(128) as i8, 72);
+        assert_eq!(200u8.to_biased(128).from_biased(128), 200);
+        for v in 0..=255u8 {
+            assert_eq!(v.to_biased(128) as i8 as i32, v as i32 - 128);
+            assert_eq!(v.to_biased(128).from_biased(128), v);
+        }
+        assert_eq!(50u8.to_biased(128) as i8, -78);
+        assert_eq!(0u8.to_biased(128) as i8, -128);
+        assert_eq!((-78i8 as u8).from_biased(128), 50);
+        assert_eq!(40000u16.to_biased(32768), 7232);
+        assert_eq!(1000u16.to_biased(32768) as i16, -31768);
+        assert_eq!(i16::MIN.to_biased(1), i16::MIN);
+        assert_eq!((-72i32).from_biased(128), 56);
+    }
+
+    #[test]
+    fn test_array_padding() {
+        assert_eq!(array_padding(12, 5, 64), Some(4));
+        assert_eq!(array_padding(12, 0, 64), Some(0));
+        assert_eq!(array_padding(3, 3, 4), Some(3));
+        assert_eq!(array_padding(3, 3, 1), Some(0));
+        assert_eq!(array_padding(3, 3, 0), None);
+        assert_eq!(array_padding(usize::MAX / 2 + 1, 2, 64), None);
+        for n in 0..100 {
+            let pad = array_padding(12, n, 16).unwrap();
+            assert_eq!(12 * n + pad, (12 * n).align_upwards(16));
+        }
+    }
+
+    #[test]
+    fn test_piecewise_map() {
+        let curve = [(0.0f64, 0.0), (1.0, 10.0), (2.0, 40.0)];
+        assert_eq!(0.5.piecewise_map(&curve), 5.0);
+        assert_eq!(1.0.piecewise_map(&curve), 10.0);
+        assert_eq!(1.75.piecewise_map(&curve), 32.5);
+        assert_eq!((-1.0).piecewise_map(&curve), 0.0);
+        assert_eq!(9.0.piecewise_map(&curve), 40.0);
+        assert_eq!(0.5f32.piecewise_map(&[(1.0, 3.0)]), 3.0);
+        assert_eq!(0.5f32.piecewise_map(&[]), 0.5);
+        let step = [(0.0f32, 0.0), (1.0, 0.0), (1.0, 1.0), (2.0, 1.0)];
+        assert_eq!(0.5f32.piecewise_map(&step), 0.0);
+        assert_eq!(1.5f32.piecewise_map(&step), 1.0);
+    }
+
+    #[test]
+    fn test_saturating_add_reported() {
+        assert_eq!(95u32.saturating_add_reported(10, 99), (99, true));
+        assert_eq!(95u32.saturating_add_reported(4, 99), (99, false));
+        assert_eq!(0u16.saturating_add_reported(0, 0), (0, false));
+        assert_eq!(250u8.saturating_add_reported(10, u8::MAX), (u8::MAX, true));
+        assert_eq!(
+            u64::MAX.saturating_add_reported(0, u64::MAX),
+            (u64::MAX, false)
+        );
+        assert_eq!(150usize.saturating_add_reported(0, 100), (100, true));
+    }
+
+    #[test]
+    fn test_quantize() {
+        for i in 0..=1000 {
+            let v = i as f64 / 1000.0;
+            assert_eq!(v.quantize_dithered(256, 0.0), v.quantize(256));
+            assert_eq!(v.quantize(256), (v * 255.0).round() as u32);
+        }
+        assert_eq!(0.0f32.quantize(256), 0);
+        assert_eq!(1.0f32.quantize(256), 255);
+        assert_eq!((-0.5f32).quantize(256), 0);
+        assert_eq!(f32::NAN.quantize(256), 0);
+        assert_eq!(0.5f32.quantize(0), 0);
+        assert_eq!(0.5f32.quantize(1), 0);
+        assert_eq!(1.0f32.quantize_dithered(256, 1.0), 255);
+        assert_eq!(0.0f32.quantize_dithered(256, -1.0), 0);
+        let avg: f64 = [-0.75f32, -0.25, 0.25, 0.75]
+            .iter()
+            .map(|&n| (0.5f32 / 255.0).quantize_dithered(256, n) as f64)
+            .sum::<f64>()
+            / 4.0;
+        assert_eq!(avg, 0.5);
+    }
+
+    #[test]
+    fn test_bisect_max() {
+        assert_eq!(u32::bisect_max(0, 100, |n| n * n <= 100), Some(10));
+        assert_eq!(u32::bisect_max(0, 100, |_| true), Some(100));
+        assert_eq!(u32::bisect_max(5, 100, |n| n < 5), None);
+        assert_eq!(u32::bisect_max(5, 4, |_| true), None);
+        assert_eq!(i8::bisect_max(i8::MIN, i8::MAX, |n| n <= -3), Some(-3));
+        assert_eq!(i8::bisect_max(i8::MIN, i8::MAX, |_| true), Some(i8::MAX));
+        assert_eq!(
+            u64::bisect_max(0, u64::MAX, |n| n <= 1 << 40),
+            Some(1 << 40)
+        );
+    }
+
+    #[test]
+    fn test_checked_align_upwards() {
+        assert_eq!(250u8.checked_align_upwards(64), None);
+        assert_eq!(u8::MAX.checked_align_upwards(2), None);
+        assert_eq!(u8::MAX.checked_align_upwards(1), Some(u8::MAX));
+        assert_eq!(192u8.checked_align_upwards(64), Some(192));
+        assert_eq!(191u8.checked_align_upwards(64), Some(192));
+        assert_eq!(0u32.checked_align_upwards(16), Some(0));
+        assert_eq!(17u32.checked_align_upwards(0), None);
+        assert_eq!(usize::MAX.checked_align_upwards(4096), None);
+        assert_eq!(120i8.checked_align_upwards(16), None);
+        assert_eq!(
+            (-5i32).checked_align_upwards(4),
+            Some((-5i32).align_upwards(4))
+        );
+        for v in 0..=200u8 {
+            assert_eq!(v.checked_align_upwards(50), Some(v.align_upwards(50)));
+        }
+    }
+
+    #[test]
+    fn test_saturating_align_upwards() {
+        assert_eq!(u16::MAX.saturating_align_upwards(1024), u16::MAX);
+        assert_eq!(64000u16.saturating_align_upwards(1024), 64512);
+        assert_eq!(65000u16.saturating_align_upwards(1024), u16::MAX);
+        assert_eq!(i32::MAX.saturating_align_upwards(8), i32::MAX);
+        assert_eq!((i32::MAX - 7).saturating_align_upwards(8), i32::MAX - 7);
+        assert_eq!(4096usize.saturating_align_upwards(4096), 4096);
+        assert_eq!(
+            (-5i32).saturating_align_upwards(4),
+            (-5i32).align_upwards(4)
+        );
+    }
+
+    #[test]
+    fn test_align_region() {
+        assert_eq!(align_region(100, 50, 64), Some((64, 128)));
+        assert_eq!(align_region(0, 0, 64), Some((0, 0)));
+        assert_eq!(align_region(100, 0, 64), Some((64, 64)));
+        assert_eq!(align_region(128, 64, 64), Some((128, 64)));
+        assert_eq!(align_region(1, 1, 1), Some((1, 1)));
+        assert_eq!(align_region(100, 50, 0), None);
+        assert_eq!(align_region(usize::MAX, 1, 64), None);
+        assert_eq!(align_region(usize::MAX - 10, 5, 64), None);
+        assert_eq!(
+            align_region(usize::MAX - 127, 64, 64),
+            Some((usize::MAX - 127, 64))
+        );
+        assert_eq!(align_region(usize::MAX - 63, 64, 64), None);
+    }
+
+    #[test]
+    fn test_try_align() {
+        assert_eq!(63u32.try_align_downwards(0), None);
+        assert_eq!(63u32.try_align_upwards(0), None);
+        assert_eq!(63u32.try_align_downwards(64), Some(0));
+        assert_eq!(63u32.try_align_upwards(64), Some(64));
+        assert_eq!(128u8.try_align_upwards(64), Some(128));
+        assert_eq!((-65i64).try_align_downwards(64), Some(-64));
+        assert_eq!(0usize.try_align_upwards(0), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_decibels() {
+        assert!((f32::from_db(0.5f32.to_db()) - 0.5).abs() < 1e-6);
+        assert!((f64::from_db(0.5f64.to_db()) - 0.5).abs() < 1e-6);
+        assert_eq!(10.0f64.to_db(), 20.0);
+        assert_eq!(0.0f32.to_db(), -120.0);
+        assert_eq!((-1.0f64).to_db(), -120.0);
+        assert_eq!(f32::NAN.to_db(), -120.0);
+        assert_eq!(1e-9f32.to_db(), -120.0);
+        assert!((f32::from_db(-120.0) - 1e-6).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_align_pow2() {
+        for v in 0..=200u8 {
+            for &align in &[1u8, 2, 4, 8, 16, 32] {
+                assert_eq!(v.align_downwards_pow2(align), v.align_downwards(align));
+                assert_eq!(v.align_upwards_pow2(align), v.align_upwards(align));
+            }
+        }
+        assert_eq!(4097usize.align_upwards_pow2(4096), 8192);
+        assert_eq!(4097usize.align_downwards_pow2(4096), 4096);
+        assert_eq!(5i32.align_upwards_pow2(4), 8);
+        assert_eq!((-5i32).align_downwards_pow2(4), -8);
+        assert_eq!((-5i32).align_upwards_pow2(4), -4);
+    }
+
+    #[test]
+    #[should_panic(expected = "align must be a power of two")]
+    #[cfg(debug_assertions)]
+    fn test_align_pow2_not_pow2() {
+        12u32.align_upwards_pow2(12);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_to_digits() {
+        assert_eq!(255u32.to_digits(16), [15, 15]);
+        assert_eq!(255u32.to_digits(10), [2, 5, 5]);
+        assert_eq!(255u8.to_digits(2), [1; 8]);
+        assert_eq!(0u64.to_digits(10), [0]);
+        assert_eq!(u64::MAX.to_digits(10).len(), 20);
+        assert_eq!(9usize.to_digits(10), [9]);
+        assert_eq!(10u16.to_digits(10), [1, 0]);
+        assert!(5u32.to_digits(0).is_empty());
+        assert!(5u32.to_digits(1).is_empty());
+    }
+
+    #[test]
+    fn test_ewma_variance() {
+        let mut ewma = EwmaVariance::new(0.1);
+        assert_eq!(ewma.update(10.0), (10.0, 0.0));
+        for i in 0..200 {
+            ewma.update(10.0 + if i % 2 == 0 { 0.5 } else { -0.5 });
+        }
+        let (mean, var) = (ewma.mean(), ewma.variance());
+        assert!((mean - 10.0).abs() < 0.1);
+        assert!(var > 0.1 && var < 0.5);
+        let spike = 20.0;
+        assert!((spike - mean).abs() > 6.0 * var.sqrt());
+        let (_, after) = ewma.update(spike);
+        assert!(after > var);
+        assert_eq!(EwmaVariance::new(2.0), EwmaVariance::new(1.0));
+    }
+
+    #[test]
+    fn test_is_near() {
+        assert!((-2i32).is_near(2, 4));
+        assert!(2i32.is_near(-2, 4));
+        assert!(!(-3i32).is_near(2, 4));
+        assert!(i32::MIN.is_near(i32::MIN, 0));
+        assert!(!i32::MIN.is_near(i32::MAX, i32::MAX));
+        assert!(!5i32.is_near(5, -1));
+        assert!(0u64.is_near(u64::MAX, u64::MAX));
+        assert!(!0u8.is_near(255, 254));
+        let eps = 1e-9;
+        assert!((0.1f64 + 0.2).is_near(0.3, eps));
+        assert!(!0.3f64.is_near(0.3 + 1e-6, eps));
+        assert!((-1e-10f64).is_near(1e-10, eps));
+        assert!(!f64::NAN.is_near(0.0, f64::INFINITY));
+    }
+
+    #[test]
+    fn test_hysteresis() {
+        let mut gate = Hysteresis::new(-1.0, 1.0);
+        for i in 0..100 {
+            assert!(!gate.update(if i % 2 == 0 { 0.9 } else { -0.9 }));
+        }
+        assert!(gate.update(1.5));
+        for i in 0..100 {
+            assert!(gate.update(if i % 2 == 0 { 0.9 } else { -0.9 }));
+        }
+        assert!(gate.update(-1.0));
+        assert!(!gate.update(-1.01));
+        assert!(!gate.is_on());
+        assert!(!gate.update(1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "low threshold must be less than high")]
+    fn test_hysteresis_invalid() {
+        Hysteresis::new(1.0, 1.0);
+    }
+
+    #[test]
+    fn test_is_in_range_exclusive() {
+        for a in 0..10u32 {
+            assert_eq!(a.is_in_range_exclusive(3, 7), (3..7).contains(&a));
+            assert_eq!(a.is_in_open_range(3, 7), (4..7).contains(&a));
+        }
+        assert!(!5i32.is_in_range_exclusive(5, 5));
+        assert!(!5i32.is_in_open_range(5, 6));
+        assert!((-1i8).is_in_range_exclusive(-1, 0));
+        assert!(0.5f64.is_in_open_range(0.0, 1.0));
+        assert!(0.0f32.is_in_range_exclusive(0.0, 1.0));
+        assert!(!1.0f32.is_in_range_exclusive(0.0, 1.0));
+        assert!(!f64::NAN.is_in_range_exclusive(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_wrap_in_range() {
+        assert_eq!(370i32.wrap_in_range(0, 360), 10);
+        assert_eq!((-10i32).wrap_in_range(0, 360), 350);
+        assert_eq!((-720i32).wrap_in_range(0, 360), 0);
+        assert_eq!(360i32.wrap_in_range(0, 360), 0);
+        assert_eq!(200u8.wrap_in_range(10, 20), 10);
+        assert_eq!(5u8.wrap_in_range(10, 20), 15);
+        assert_eq!(i64::MIN.wrap_in_range(i64::MIN, i64::MAX), i64::MIN);
+        assert_eq!(i64::MAX.wrap_in_range(i64::MIN, i64::MAX), i64::MIN);
+        assert_eq!(u64::MAX.wrap_in_range(0, u64::MAX), 0);
+        assert_eq!(7i32.wrap_in_range(3, 3), 3);
+        assert_eq!(7i32.wrap_in_range(5, 3), 5);
+        assert_eq!(370.0f64.wrap_in_range(0.0, 360.0), 10.0);
+        assert_eq!((-10.0f64).wrap_in_range(0.0, 360.0), 350.0);
+        assert_eq!((-1e-30f64).wrap_in_range(0.0, 360.0), 0.0);
+        assert_eq!(190.0f32.wrap_in_range(-180.0, 180.0), -170.0);
+        assert_eq!(1.0f32.wrap_in_range(2.0, 2.0), 2.0);
+        assert_eq!(1.0f32.wrap_in_range(0.0, f32::NAN), 0.0);
+        assert!(f32::NAN.wrap_in_range(0.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_ring_capacity() {
+        assert_eq!(ring_capacity(0), 1);
+        assert_eq!(ring_capacity(1), 1);
+        assert_eq!(ring_capacity(3), 4);
+        assert_eq!(ring_capacity(1000), 1024);
+        assert_eq!(ring_capacity(1025), 2048);
+        let top = usize::MAX / 2 + 1;
+        assert_eq!(ring_capacity(top), top);
+        assert_eq!(ring_capacity(top + 1), top);
+        assert_eq!(ring_capacity(usize::MAX), top);
+    }
+
+    #[test]
+    fn test_map_range() {
+        assert_eq!(0.0f32.map_range((0.0, 1023.0), (0.0, 1.0)), 0.0);
+        assert_eq!(1023.0f32.map_range((0.0, 1023.0), (0.0, 1.0)), 1.0);
+        assert_eq!(0.25f64.map_range((0.0, 1.0), (-1.0, 1.0)), -0.5);
+        assert_eq!(2.0f64.map_range((0.0, 1.0), (0.0, 10.0)), 20.0);
+        assert_eq!(1.0f64.map_range((2.0, 2.0), (5.0, 6.0)), 5.0);
+        assert_eq!(512u16.map_range((0, 1023), (0, 255)), 128);
+        assert_eq!((-5i32).map_range((-10, 10), (0, 100)), 25);
+        assert_eq!(20i8.map_range((0, 10), (0, 100)), i8::MAX);
+        assert_eq!(3u8.map_range((3, 3), (9, 10)), 9);
+        assert_eq!(u64::MAX.map_range((0, u64::MAX), (0, 1)), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_gamma_lut() {
+        for &gamma in &[1.0f32, 1.8, 2.2, 0.45] {
+            let lut = GammaLut::new(gamma);
+            for &v in &[0u8, 1, 17, 64, 128, 200, 254, 255] {
+                let expected = ((v as f32 / 255.0).apply_gamma(gamma) * 255.0).round() as u8;
+                assert_eq!(lut.apply(v), expected);
+            }
+        }
+        let identity = GammaLut::new(1.0);
+        assert!((0..=255u8).all(|v| identity.apply(v) == v));
+    }
+
+    #[test]
+    fn test_lerp() {
+        assert_eq!(2.0f64.lerp(4.0, 0.5), 3.0);
+        assert_eq!(2.0f64.lerp(4.0, 0.0), 2.0);
+        assert_eq!(2.0f64.lerp(4.0, 1.0), 4.0);
+        assert_eq!(2.0f32.lerp(4.0, -1.0), 0.0);
+        assert_eq!(0i32.lerp(3, 0.5), 2);
+        assert_eq!(0i32.lerp(-3, 0.5), -2);
+        assert_eq!(3u8.lerp(0, 0.5), 2);
+        assert_eq!(10u8.lerp(20, 0.0), 10);
+        assert_eq!(10u8.lerp(20, 1.0), 20);
+        assert_eq!(10u8.lerp(20, -2.0), 0);
+        assert_eq!(200u8.lerp(250, 2.0), 255);
+        assert_eq!(100i64.lerp(-100, 0.25), 50);
+        assert_eq!(9007199254740993i64.lerp(0, 0.0), 9007199254740993);
+        assert_eq!(0i64.lerp(9007199254740993, 1.0), 9007199254740993);
+        assert_eq!(u64::MAX.lerp(0, 0.0), u64::MAX);
+        assert_eq!(i64::MIN.lerp(i64::MAX, 1.0), i64::MAX);
+        assert_eq!(i64::MAX.lerp(0, -1.0), i64::MAX);
+    }
+
+    #[test]
+    fn test_ceil_div() {
+        assert_eq!(12u32.ceil_div(4), 3);
+        assert_eq!(13u32.ceil_div(4), 4);
+        assert_eq!(0u32.ceil_div(4), 0);
+        assert_eq!(u64::MAX.ceil_div(1), u64::MAX);
+        assert_eq!(u64::MAX.ceil_div(2), u64::MAX / 2 + 1);
+        assert_eq!(u8::MAX.ceil_div(u8::MAX), 1);
+        assert_eq!(7i32.ceil_div(2), 4);
+        assert_eq!((-7i32).ceil_div(2), -3);
+        assert_eq!(7i32.ceil_div(-2), -3);
+        assert_eq!((-7i32).ceil_div(-2), 4);
+        assert_eq!(i64::MAX.ceil_div(2), i64::MAX / 2 + 1);
+        assert_eq!(i8::MIN.ceil_div(3), -42);
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor must be non-zero")]
+    fn test_ceil_div_zero() {
+        1u32.ceil_div(0);
+    }
+
+    #[test]
+    fn test_div_round() {
+        assert_eq!(12u32.div_round(4), 3);
+        assert_eq!(13u32.div_round(4), 3);
+        assert_eq!(14u32.div_round(4), 4);
+        assert_eq!(u8::MAX.div_round(2), 128);
+        assert_eq!(u64::MAX.div_round(u64::MAX), 1);
+        assert_eq!((u64::MAX - 1).div_round(u64::MAX), 1);
+        assert_eq!(5i32.div_round(2), 3);
+        assert_eq!((-5i32).div_round(2), -3);
+        assert_eq!(5i32.div_round(-2), -3);
+        assert_eq!((-5i32).div_round(-2), 3);
+        assert_eq!((-4i32).div_round(3), -1);
+        assert_eq!(i8::MIN.div_round(i8::MIN), 1);
+        assert_eq!(i8::MAX.div_round(i8::MIN), -1);
+        assert_eq!(i8::MIN.div_round(3), -43);
+        for a in -50i32..=50 {
+            for &b in &[-7i32, -2, 1, 3, 8] {
+                let expected = (a as f64 / b as f64).round() as i32;
+                assert_eq!(a.div_round(b), expected, "{} / {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor must be non-zero")]
+    fn test_div_round_zero() {
+        5i16.div_round(0);
+    }
+
+    #[test]
+    fn test_power_of_two() {
+        assert!(!IsPowerOfTwo::is_power_of_two(0u8));
+        assert!(IsPowerOfTwo::is_power_of_two(1u8));
+        assert!(IsPowerOfTwo::is_power_of_two(128u8));
+        assert!(!IsPowerOfTwo::is_power_of_two(u8::MAX));
+        assert!(IsPowerOfTwo::is_power_of_two(1u64 << 63));
+        assert_eq!(0u16.next_pow2(), Some(1));
+        assert_eq!(1u16.next_pow2(), Some(1));
+        assert_eq!(4096u16.next_pow2(), Some(4096));
+        assert_eq!(4097u16.next_pow2(), Some(8192));
+        assert_eq!((u16::MAX / 2 + 1).next_pow2(), Some(32768));
+        assert_eq!((u16::MAX - 1).next_pow2(), None);
+        assert_eq!(u64::MAX.next_pow2(), None);
+        assert_eq!(0usize.prev_pow2(), None);
+        assert_eq!(1usize.prev_pow2(), Some(1));
+        assert_eq!(3usize.prev_pow2(), Some(2));
+        assert_eq!(4096u32.prev_pow2(), Some(4096));
+        assert_eq!((u32::MAX - 1).prev_pow2(), Some(1 << 31));
+        assert_eq!(u8::MAX.prev_pow2(), Some(128));
+        for v in 1..=1000u32 {
+            let p = v.prev_pow2().unwrap();
+            let n = v.next_pow2().unwrap();
+            assert!(IsPowerOfTwo::is_power_of_two(p) && IsPowerOfTwo::is_power_of_two(n));
+            assert!(p <= v && v <= n && (p == n || n == 2 * p));
+        }
+    }
+
+    #[test]
+    fn test_clamped01() {
+        assert_eq!(0.25f32.clamped01(), 0.25);
+        assert_eq!(0.0f64.clamped01(), 0.0);
+        assert_eq!(1.0f64.clamped01(), 1.0);
+        assert_eq!(f64::INFINITY.clamped01(), 1.0);
+        assert_eq!(f32::NEG_INFINITY.clamped01(), 0.0);
+        assert_eq!(f64::NAN.clamped01(), 0.0);
+    }
+
+    #[test]
+    fn test_slew_limit() {
+        assert_eq!(0i32.slew_limit(100, 10), 10);
+        let mut v = 0i32;
+        for _ in 0..10 {
+            v = v.slew_limit(100, 10);
+        }
+        assert_eq!(v, 100);
+        assert_eq!(v.slew_limit(100, 10), 100);
+        assert_eq!(100u8.slew_limit(0, 30), 70);
+        assert_eq!(5u8.slew_limit(0, 30), 0);
+        assert_eq!(i64::MIN.slew_limit(i64::MAX, i64::MAX), -1);
+        assert_eq!(10i32.slew_limit(20, -5), 10);
+        let mut f = 0.0f64;
+        let mut steps = 0;
+        while f != 1.0 {
+            f = f.slew_limit(1.0, 0.3);
+            steps += 1;
+        }
+        assert_eq!(steps, 4);
+        assert_eq!((-1.0f32).slew_limit(-3.0, 0.5), -1.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "clamped: min must be <= max")]
+    #[cfg(debug_assertions)]
+    fn test_clamped_swapped_bounds() {
+        8.clamped(9, 5);
+    }
+
+    #[test]
+    fn test_clamped_nan_bounds() {
+        assert_eq!(0.5f32.clamped(f32::NAN, 1.0), 0.5);
+        assert_eq!(2.0f64.clamped(0.0, f64::NAN), 2.0);
+        assert_eq!(5u8.clamped(5, 5), 5);
+    }
+
+    #[test]
+    fn test_alpha_blend_u8() {
+        for a in 0..=255u8 {
+            for b in (0..=255u8).step_by(5) {
+                assert_eq!(a.alpha_blend_u8(b, 0), a);
+                assert_eq!(a.alpha_blend_u8(b, 255), b);
+                for &alpha in &[1u8, 64, 127, 128, 200, 254] {
+                    let exact = (a as f64 * (255 - alpha) as f64 + b as f64 * alpha as f64) / 255.0;
+                    let v = a.alpha_blend_u8(b, alpha) as f64;
+                    assert!((v - exact).abs() <= 0.5, "{} {} {}", a, b, alpha);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_linspace() {
+        assert_eq!(f64::linspace(0.0, 1.0, 5), [0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(f32::linspace(1.0, -1.0, 3), [1.0, 0.0, -1.0]);
+        assert!(f64::linspace(0.0, 1.0, 0).is_empty());
+        assert_eq!(f64::linspace(3.0, 9.0, 1), [3.0]);
+        assert_eq!(f32::linspace(0.0, 1.0, 2), [0.0, 1.0]);
+        let v = f64::linspace(0.1, 0.7, 1000);
+        assert_eq!(v.len(), 1000);
+        assert_eq!(v[0], 0.1);
+        assert_eq!(v[999], 0.7);
+        assert!(v.windows(2).all(|w| w[1] > w[0]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_exp_ramp() {
+        let v = f64::exp_ramp(0.001, 1.0, 16);
+        assert_eq!(v.len(), 16);
+        assert_eq!(v[0], 0.001);
+        assert_eq!(v[15], 1.0);
+        let ratio = v[1] / v[0];
+        assert!(v.windows(2).all(|w| (w[1] / w[0] - ratio).abs() < 1e-9));
+        let v = f32::exp_ramp(1.0, 0.25, 3);
+        assert_eq!(v, [1.0, 0.5, 0.25]);
+        assert!(f64::exp_ramp(1.0, 2.0, 0).is_empty());
+        assert_eq!(f64::exp_ramp(3.0, 9.0, 1), [3.0]);
+        assert_eq!(f64::exp_ramp(0.0, 1.0, 5), f64::linspace(0.0, 1.0, 5));
+        assert_eq!(f32::exp_ramp(-1.0, 1.0, 3), [-1.0, 0.0, 1.0]);
     }
 }
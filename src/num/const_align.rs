@@ -0,0 +1,65 @@
+//! 可在常量上下文中使用的对齐函数。
+//!
+//! 契定方法无法声明为 `const fn`，因此在此为常用的整数类型提供等价的常量函数。
+//! 与 [AlignUpwards] 及 [AlignDownwards] 相同，`align` 为 `0` 时会 panic，
+//! 在常量上下文中则表现为编译错误。
+//!
+//! [AlignUpwards]: ../trait.AlignUpwards.html
+//! [AlignDownwards]: ../trait.AlignDownwards.html
+//!
+//! # Examples
+//!
+//! ```
+//! use pavo_traits::const_align::align_up_usize;
+//!
+//! const DATA_LEN: usize = 100;
+//! const BUF: usize = align_up_usize(DATA_LEN, 64);
+//! assert_eq!(BUF, 128);
+//! ```
+
+macro_rules! impl_const_align {
+    ($Type:ty, $up:ident, $down:ident) => {
+        #[doc = concat!("将 `", stringify!($Type), "` 数值向上对齐到指定倍数。")]
+        pub const fn $up(value: $Type, align: $Type) -> $Type {
+            let rem = value % align;
+            if rem != 0 {
+                value + align - rem
+            } else {
+                value
+            }
+        }
+
+        #[doc = concat!("将 `", stringify!($Type), "` 数值向下对齐到指定倍数。")]
+        pub const fn $down(value: $Type, align: $Type) -> $Type {
+            value - value % align
+        }
+    };
+}
+
+impl_const_align!(i32, align_up_i32, align_down_i32);
+impl_const_align!(i64, align_up_i64, align_down_i64);
+impl_const_align!(isize, align_up_isize, align_down_isize);
+impl_const_align!(u32, align_up_u32, align_down_u32);
+impl_const_align!(u64, align_up_u64, align_down_u64);
+impl_const_align!(usize, align_up_usize, align_down_usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::num::{AlignDownwards, AlignUpwards};
+
+    #[test]
+    fn test_const_align() {
+        const UP: usize = align_up_usize(100, 64);
+        const DOWN: usize = align_down_usize(100, 64);
+        assert_eq!((UP, DOWN), (128, 64));
+        for v in 0..300u32 {
+            assert_eq!(align_up_u32(v, 24), v.align_upwards(24));
+            assert_eq!(align_down_u32(v, 24), v.align_downwards(24));
+        }
+        assert_eq!(align_up_i32(-5, 4), (-5i32).align_upwards(4));
+        assert_eq!(align_down_isize(-5, 4), (-5isize).align_downwards(4));
+        assert_eq!(align_up_u64(u64::MAX - 1, 1), u64::MAX - 1);
+        assert_eq!(align_down_u64(u64::MAX, 1 << 32), u64::MAX << 32);
+    }
+}
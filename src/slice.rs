@@ -1,38 +1,1532 @@
 //! 数组类契定。
-//！
+//!
 
-trait CloneFromSliceFlex<T: Clone> {
+use crate::convert::AsBytes;
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float::FloatExt;
+use crate::num::{AlignDownwards, Clamped, HammingDistance};
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// 定义从不同长度的数组克隆元素的契定。
+pub trait CloneFromSliceFlex<T: Clone> {
     /// Copies the elements from `src` into `self`.
     ///
     /// The length of `src` could be different to `self`.
     fn clone_from_slice_flex(&mut self, src: &[T]);
 }
 
-impl<T: Clone> CloneFromSliceFlex<T> for [T] {
-    fn clone_from_slice_flex(&mut self, src: &[T]) {
-        let len = self.len().min(src.len());
-        for i in 0..len {
-            self[i].clone_from(&src[i]);
+impl<T: Clone> CloneFromSliceFlex<T> for [T] {
+    fn clone_from_slice_flex(&mut self, src: &[T]) {
+        let len = self.len().min(src.len());
+        for i in 0..len {
+            self[i].clone_from(&src[i]);
+        }
+    }
+}
+
+/// 定义从不同长度的数组拷贝元素的契定。
+pub trait CopyFromSliceFlex<T: Copy> {
+    /// Copies all elements from `src` into `self`, using a memcpy.
+    ///
+    /// The length of `src` could be different to `self`.
+    fn copy_from_slice_flex(&mut self, src: &[T]);
+}
+
+impl<T: Copy> CopyFromSliceFlex<T> for [T] {
+    fn copy_from_slice_flex(&mut self, src: &[T]) {
+        let len = self.len().min(src.len());
+        unsafe {
+            core::ptr::copy_nonoverlapping(src.as_ptr(), self.as_mut_ptr(), len);
+        }
+    }
+}
+
+/// 定义计算滑动平均值的契定。
+#[cfg(feature = "alloc")]
+pub trait MovingAverage {
+    /// 返回窗口大小为 `window` 的简单滑动平均值序列。
+    ///
+    /// 仅对窗口完整的位置输出结果，`window` 为 `0` 或大于长度时返回空序列。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{MovingAverage};
+    ///
+    /// assert_eq!([1, 2, 3, 4, 5].moving_average(3), [2.0, 3.0, 4.0]);
+    /// assert!([1, 2, 3].moving_average(0).is_empty());
+    /// assert!([1, 2, 3].moving_average(4).is_empty());
+    /// ```
+    fn moving_average(&self, window: usize) -> Vec<f64>;
+}
+
+macro_rules! impl_moving_average {
+    ($Type:ty) => {
+        #[cfg(feature = "alloc")]
+        impl MovingAverage for [$Type] {
+            fn moving_average(&self, window: usize) -> Vec<f64> {
+                if window == 0 || window > self.len() {
+                    return Vec::new();
+                }
+                let mut sum: f64 = self[..window].iter().map(|&x| x as f64).sum();
+                let mut out = Vec::with_capacity(self.len() - window + 1);
+                out.push(sum / window as f64);
+                for (&new, &old) in self[window..].iter().zip(self.iter()) {
+                    sum += new as f64 - old as f64;
+                    out.push(sum / window as f64);
+                }
+                out
+            }
+        }
+    };
+}
+
+impl_moving_average!(i8);
+impl_moving_average!(i16);
+impl_moving_average!(i32);
+impl_moving_average!(i64);
+impl_moving_average!(isize);
+impl_moving_average!(u8);
+impl_moving_average!(u16);
+impl_moving_average!(u32);
+impl_moving_average!(u64);
+impl_moving_average!(usize);
+impl_moving_average!(f32);
+impl_moving_average!(f64);
+
+/// 定义按逐元素上下限约束数组的契定。
+pub trait ClampBetween<T> {
+    /// 将 `self[i]` 限制在 `[mins[i], maxs[i]]` 的范围之内。
+    ///
+    /// 三者长度不同时仅处理公共长度部分。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{ClampBetween};
+    ///
+    /// let mut a = [5, 5, 5];
+    /// a.clamp_between(&[0, 6, 0], &[4, 10, 10]);
+    /// assert_eq!(a, [4, 6, 5]);
+    /// ```
+    fn clamp_between(&mut self, mins: &[T], maxs: &[T]);
+}
+
+impl<T: Clamped + Copy> ClampBetween<T> for [T] {
+    fn clamp_between(&mut self, mins: &[T], maxs: &[T]) {
+        for ((v, &min), &max) in self.iter_mut().zip(mins).zip(maxs) {
+            *v = v.clamped(min, max);
+        }
+    }
+}
+
+/// 定义重排交错声道顺序的契定。
+#[cfg(feature = "alloc")]
+pub trait ReorderChannels<T> {
+    /// 按 `src_order` 重排交错存储的各帧声道，返回新的交错数组。
+    ///
+    /// 输出的第 `c` 个声道取自源帧中的第 `src_order[c]` 个声道。
+    /// 当 `src_order.len() * frames` 与数组长度不符或存在越界的声道索引时返回 `None`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{ReorderChannels};
+    ///
+    /// let lr = [1, 2, 3, 4];
+    /// assert_eq!(lr.reorder_channels(&[1, 0], 2), Some(vec![2, 1, 4, 3]));
+    /// assert_eq!(lr.reorder_channels(&[1, 0], 3), None);
+    /// ```
+    fn reorder_channels(&self, src_order: &[usize], frames: usize) -> Option<Vec<T>>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> ReorderChannels<T> for [T] {
+    fn reorder_channels(&self, src_order: &[usize], frames: usize) -> Option<Vec<T>> {
+        let channels = src_order.len();
+        if channels.checked_mul(frames)? != self.len() || src_order.iter().any(|&c| c >= channels) {
+            return None;
+        }
+        let mut out = Vec::with_capacity(self.len());
+        for frame in self.chunks_exact(channels.max(1)) {
+            out.extend(src_order.iter().map(|&c| frame[c].clone()));
+        }
+        Some(out)
+    }
+}
+
+/// 定义计算两个数组之间汉明距离的契定。
+pub trait HammingDistanceSlice<T> {
+    /// 返回两个数组逐元素汉明距离之和，长度不同时返回 `None`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{HammingDistanceSlice};
+    ///
+    /// assert_eq!([0b1010u8, 0xFF].hamming_distance_slice(&[0b0110, 0x00]), Some(10));
+    /// assert_eq!([0u8].hamming_distance_slice(&[0, 0]), None);
+    /// ```
+    fn hamming_distance_slice(&self, other: &[T]) -> Option<u32>;
+}
+
+impl<T: HammingDistance + Copy> HammingDistanceSlice<T> for [T] {
+    fn hamming_distance_slice(&self, other: &[T]) -> Option<u32> {
+        if self.len() != other.len() {
+            return None;
+        }
+        Some(self.iter().zip(other).fold(0u32, |acc, (&a, &b)| {
+            acc.saturating_add(a.hamming_distance(b))
+        }))
+    }
+}
+
+/// 定义统计数组首尾连续相等元素个数的契定。
+pub trait LeadingTrailingCount<T> {
+    /// 返回数组开头连续等于 `value` 的元素个数。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{LeadingTrailingCount};
+    ///
+    /// assert_eq!([0, 0, 5, 0].leading_count(&0), 2);
+    /// assert_eq!([0, 0, 0].leading_count(&0), 3);
+    /// ```
+    fn leading_count(&self, value: &T) -> usize;
+
+    /// 返回数组末尾连续等于 `value` 的元素个数。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{LeadingTrailingCount};
+    ///
+    /// assert_eq!([0, 0, 5, 0].trailing_count(&0), 1);
+    /// assert_eq!([0, 0, 0].trailing_count(&0), 3);
+    /// ```
+    fn trailing_count(&self, value: &T) -> usize;
+}
+
+impl<T: PartialEq> LeadingTrailingCount<T> for [T] {
+    fn leading_count(&self, value: &T) -> usize {
+        self.iter().take_while(|x| *x == value).count()
+    }
+
+    fn trailing_count(&self, value: &T) -> usize {
+        self.iter().rev().take_while(|x| *x == value).count()
+    }
+}
+
+/// 定义将数组均分为若干份的契定。
+#[cfg(feature = "alloc")]
+pub trait SplitIntoParts<T> {
+    /// 将数组按顺序切分为 `n` 个长度尽量相等的连续子数组。
+    ///
+    /// 无法整除时靠前的子数组多一个元素；`n` 大于数组长度时末尾的子数组为空，
+    /// `n` 为 `0` 时返回空序列。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{SplitIntoParts};
+    ///
+    /// let a = [0; 10];
+    /// let lens: Vec<usize> = a.split_into_parts(3).iter().map(|p| p.len()).collect();
+    /// assert_eq!(lens, [4, 3, 3]);
+    /// assert_eq!([1, 2].split_into_parts(3), [&[1][..], &[2], &[]]);
+    /// ```
+    fn split_into_parts(&self, n: usize) -> Vec<&[T]>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SplitIntoParts<T> for [T] {
+    fn split_into_parts(&self, n: usize) -> Vec<&[T]> {
+        if n == 0 {
+            return Vec::new();
+        }
+        let (base, rem) = (self.len() / n, self.len() % n);
+        let mut parts = Vec::with_capacity(n);
+        let mut rest = self;
+        for i in 0..n {
+            let (head, tail) = rest.split_at(base + (i < rem) as usize);
+            parts.push(head);
+            rest = tail;
+        }
+        parts
+    }
+}
+
+/// 定义计算方差及标准差的契定。
+pub trait Variance {
+    /// 返回样本方差（除以 `n - 1`），元素少于 `2` 个时返回 `None`。
+    ///
+    /// 使用 Welford 算法计算以保证数值稳定性。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Variance};
+    ///
+    /// let a = [2.0f64, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    /// assert!((a.variance().unwrap() - 32.0 / 7.0).abs() < 1e-12);
+    /// assert_eq!([1.0f32].variance(), None);
+    /// ```
+    fn variance(&self) -> Option<f64>;
+
+    /// 返回样本标准差，即样本方差的平方根。
+    ///
+    /// 需要启用 `std` 特性。
+    #[cfg(feature = "std")]
+    fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+}
+
+macro_rules! impl_variance {
+    ($Type:ty) => {
+        impl Variance for [$Type] {
+            fn variance(&self) -> Option<f64> {
+                if self.len() < 2 {
+                    return None;
+                }
+                let (mut mean, mut m2) = (0.0f64, 0.0f64);
+                for (i, &x) in self.iter().enumerate() {
+                    let x = x as f64;
+                    let delta = x - mean;
+                    mean += delta / (i + 1) as f64;
+                    m2 += delta * (x - mean);
+                }
+                Some(m2 / (self.len() - 1) as f64)
+            }
+        }
+    };
+}
+
+impl_variance!(f32);
+impl_variance!(f64);
+
+/// 定义按百分位缩尾（Winsorize）数组的契定。
+#[cfg(feature = "alloc")]
+pub trait Winsorize {
+    /// 将低于第 `low_pct` 百分位及高于第 `high_pct` 百分位的元素限制为对应的百分位值。
+    ///
+    /// 百分位值按最近秩（`round(pct / 100 * (len - 1))`）从排序后的副本中选取。
+    /// 当百分位不在 `[0, 100]` 之内或 `low_pct >= high_pct` 时返回 `false` 且不修改数组。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Winsorize};
+    ///
+    /// let mut a = [100, 1, 2, 3, 4, 5, 6, 7, 8, -100];
+    /// assert!(a.winsorize(10.0, 90.0));
+    /// assert_eq!(a, [8, 1, 2, 3, 4, 5, 6, 7, 8, 1]);
+    /// assert!(!a.winsorize(90.0, 10.0));
+    /// ```
+    fn winsorize(&mut self, low_pct: f64, high_pct: f64) -> bool;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clamped + PartialOrd + Copy> Winsorize for [T] {
+    fn winsorize(&mut self, low_pct: f64, high_pct: f64) -> bool {
+        if !(0.0..=100.0).contains(&low_pct)
+            || !(0.0..=100.0).contains(&high_pct)
+            || low_pct >= high_pct
+        {
+            return false;
+        }
+        if self.is_empty() {
+            return true;
+        }
+        let mut sorted = self.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+        let rank = |pct: f64| (pct / 100.0 * (sorted.len() - 1) as f64).round() as usize;
+        let (low, high) = (sorted[rank(low_pct)], sorted[rank(high_pct)]);
+        for v in self.iter_mut() {
+            *v = v.clamped(low, high);
+        }
+        true
+    }
+}
+
+/// 定义将布尔数组压缩为比特掩码的契定。
+pub trait PackBits {
+    /// 将每个布尔值依次压缩为 `dst` 中的一个比特（每字节低位在前），返回写入的字节数。
+    ///
+    /// `dst` 空间不足时仅压缩能够容纳的部分，最后一个字节中未使用的比特位被清零。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{PackBits};
+    ///
+    /// let bits = [true, false, true, true, false, false, false, false, false, true];
+    /// let mut dst = [0u8; 2];
+    /// assert_eq!(bits.pack_bits_into(&mut dst), 2);
+    /// assert_eq!(dst, [0b0000_1101, 0b0000_0010]);
+    /// ```
+    fn pack_bits_into(&self, dst: &mut [u8]) -> usize;
+}
+
+impl PackBits for [bool] {
+    fn pack_bits_into(&self, dst: &mut [u8]) -> usize {
+        let mut written = 0;
+        for (byte, bits) in dst.iter_mut().zip(self.chunks(8)) {
+            *byte = bits
+                .iter()
+                .enumerate()
+                .fold(0u8, |acc, (i, &b)| acc | ((b as u8) << i));
+            written += 1;
+        }
+        written
+    }
+}
+
+/// 将 `src` 中按每字节低位在前压缩的比特解码为 `count` 个布尔值。
+///
+/// `src` 中的比特数不足 `count` 时仅解码可用的部分。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{unpack_bits};
+///
+/// assert_eq!(unpack_bits(&[0b0000_0101], 3), [true, false, true]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn unpack_bits(src: &[u8], count: usize) -> Vec<bool> {
+    let count = count.min(src.len().saturating_mul(8));
+    (0..count)
+        .map(|i| src[i / 8] & (1 << (i % 8)) != 0)
+        .collect()
+}
+
+/// 将 `(值, 位宽)` 序列按高位在前依次打包为字节流，末尾不足一字节的部分以 `0` 补齐。
+///
+/// 每个值只取低 `位宽` 位，字段可跨越字节边界；位宽超过 `64` 时多出的高位以 `0` 填充。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{encode_fields};
+///
+/// assert_eq!(encode_fields(&[(0b101, 3), (0b11, 2)]), [0b1011_1000]);
+/// assert_eq!(encode_fields(&[(0xABC, 12), (0x5, 4)]), [0xAB, 0xC5]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_fields(values: &[(u64, u32)]) -> Vec<u8> {
+    let total: u64 = values.iter().map(|&(_, width)| width as u64).sum();
+    let mut out = Vec::with_capacity(crate::num::DivCeil::ceil_div(total, 8) as usize);
+    let mut byte = 0u8;
+    let mut filled = 0;
+    for &(value, width) in values {
+        for i in (0..width).rev() {
+            let bit = i < 64 && (value >> i) & 1 != 0;
+            byte = (byte << 1) | bit as u8;
+            filled += 1;
+            if filled == 8 {
+                out.push(byte);
+                byte = 0;
+                filled = 0;
+            }
+        }
+    }
+    if filled > 0 {
+        out.push(byte << (8 - filled));
+    }
+    out
+}
+
+/// 定义计算两个数组点积的契定。
+pub trait Dot<T> {
+    /// 返回两个数组在公共长度内逐元素乘积之和，整数类型使用饱和运算。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Dot};
+    ///
+    /// assert_eq!([1, 2, 3].dot(&[4, 5, 6]), 32);
+    /// assert_eq!([1, 2, 3].dot(&[4, 5]), 14);
+    /// ```
+    fn dot(&self, other: &[T]) -> T;
+
+    /// 同 [dot]，但两个数组长度不同时返回 `None`。
+    ///
+    /// [dot]: #tymethod.dot
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Dot};
+    ///
+    /// assert_eq!([1, 2, 3].dot_strict(&[4, 5, 6]), Some(32));
+    /// assert_eq!([1, 2, 3].dot_strict(&[4, 5]), None);
+    /// ```
+    fn dot_strict(&self, other: &[T]) -> Option<T>;
+}
+
+macro_rules! impl_dot {
+    ($Type:ty) => {
+        impl Dot<$Type> for [$Type] {
+            fn dot(&self, other: &[$Type]) -> $Type {
+                self.iter().zip(other).fold(0, |acc: $Type, (&a, &b)| {
+                    acc.saturating_add(a.saturating_mul(b))
+                })
+            }
+
+            fn dot_strict(&self, other: &[$Type]) -> Option<$Type> {
+                if self.len() == other.len() {
+                    Some(self.dot(other))
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_dot_float {
+    ($Type:ty) => {
+        impl Dot<$Type> for [$Type] {
+            fn dot(&self, other: &[$Type]) -> $Type {
+                self.iter().zip(other).map(|(&a, &b)| a * b).sum()
+            }
+
+            fn dot_strict(&self, other: &[$Type]) -> Option<$Type> {
+                if self.len() == other.len() {
+                    Some(self.dot(other))
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+impl_dot!(i8);
+impl_dot!(i16);
+impl_dot!(i32);
+impl_dot!(i64);
+impl_dot!(isize);
+impl_dot!(u8);
+impl_dot!(u16);
+impl_dot!(u32);
+impl_dot!(u64);
+impl_dot!(usize);
+impl_dot_float!(f32);
+impl_dot_float!(f64);
+
+/// 定义查找数组局部极大值的契定。
+#[cfg(feature = "alloc")]
+pub trait FindPeaks<T> {
+    /// 返回严格大于左右相邻元素且高出两者均不少于 `min_prominence` 的元素索引。
+    ///
+    /// 首尾元素只有一侧相邻元素，不视为峰值；连续相等的平台也不视为峰值。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{FindPeaks};
+    ///
+    /// assert_eq!([0, 5, 1, 2, 9, 3].find_peaks(2), [1, 4]);
+    /// assert_eq!([0, 5, 4, 2, 9, 3].find_peaks(2), [4]);
+    /// ```
+    fn find_peaks(&self, min_prominence: T) -> Vec<usize>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> FindPeaks<T> for [T]
+where
+    T: PartialOrd + Copy + core::ops::Sub<Output = T>,
+{
+    fn find_peaks(&self, min_prominence: T) -> Vec<usize> {
+        self.windows(3)
+            .enumerate()
+            .filter(|(_, w)| {
+                w[1] > w[0]
+                    && w[1] > w[2]
+                    && w[1] - w[0] >= min_prominence
+                    && w[1] - w[2] >= min_prominence
+            })
+            .map(|(i, _)| i + 1)
+            .collect()
+    }
+}
+
+/// 定义计算 CRC32 校验值的契定。
+pub trait Crc32 {
+    /// 返回使用 IEEE 多项式计算的 CRC32 校验值，与 zlib 的 `crc32` 一致。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Crc32};
+    ///
+    /// assert_eq!(b"123456789".crc32(), 0xCBF4_3926);
+    /// assert_eq!(b"".crc32(), 0);
+    /// ```
+    fn crc32(&self) -> u32;
+}
+
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+}
+
+impl Crc32 for [u8] {
+    fn crc32(&self) -> u32 {
+        !self.iter().fold(!0u32, |crc, &b| {
+            CRC32_TABLE[((crc ^ b as u32) & 0xFF) as usize] ^ (crc >> 8)
+        })
+    }
+}
+
+/// 定义计算信号自相关的契定。
+pub trait Autocorrelation {
+    /// 返回延迟为 `lag` 时的自相关值，即所有有效索引上 `self[i] * self[i + lag]` 之和。
+    ///
+    /// `lag` 不小于数组长度时返回 `0`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Autocorrelation};
+    ///
+    /// let a = [1.0f32, -1.0, 1.0, -1.0];
+    /// assert_eq!(a.autocorrelation(0), 4.0);
+    /// assert_eq!(a.autocorrelation(1), -3.0);
+    /// assert_eq!(a.autocorrelation(2), 2.0);
+    /// assert_eq!(a.autocorrelation(4), 0.0);
+    /// ```
+    fn autocorrelation(&self, lag: usize) -> f64;
+}
+
+macro_rules! impl_autocorrelation {
+    ($Type:ty) => {
+        impl Autocorrelation for [$Type] {
+            fn autocorrelation(&self, lag: usize) -> f64 {
+                if lag >= self.len() {
+                    return 0.0;
+                }
+                self.iter()
+                    .zip(&self[lag..])
+                    .map(|(&a, &b)| a as f64 * b as f64)
+                    .sum()
+            }
+        }
+    };
+}
+
+impl_autocorrelation!(f32);
+impl_autocorrelation!(f64);
+
+/// 定义统计信号过零次数的契定。
+pub trait ZeroCrossings {
+    /// 返回相邻样本之间符号变化的次数。
+    ///
+    /// 值为 `0` 的样本视为沿用前一个非零样本的符号，因此不会单独计为过零；
+    /// `NaN` 样本同样被忽略。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{ZeroCrossings};
+    ///
+    /// assert_eq!([1.0f32, -1.0, 1.0, -1.0].zero_crossings(), 3);
+    /// assert_eq!([1.0f32, 0.0, -1.0].zero_crossings(), 1);
+    /// assert_eq!([1.0f32, 0.0, 1.0].zero_crossings(), 0);
+    /// ```
+    fn zero_crossings(&self) -> usize;
+
+    /// 返回过零次数除以样本数的过零率，空数组返回 `0`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{ZeroCrossings};
+    ///
+    /// assert_eq!([1.0f32, -1.0, 1.0, -1.0].zero_crossing_rate(), 0.75);
+    /// ```
+    fn zero_crossing_rate(&self) -> f64;
+}
+
+macro_rules! impl_zero_crossings {
+    ($Type:ty) => {
+        impl ZeroCrossings for [$Type] {
+            fn zero_crossings(&self) -> usize {
+                let mut count = 0;
+                let mut last: Option<bool> = None;
+                for &x in self.iter().filter(|&&x| x != 0.0 && !x.is_nan()) {
+                    let negative = x < 0.0;
+                    if last.map_or(false, |l| l != negative) {
+                        count += 1;
+                    }
+                    last = Some(negative);
+                }
+                count
+            }
+
+            fn zero_crossing_rate(&self) -> f64 {
+                if self.is_empty() {
+                    0.0
+                } else {
+                    self.zero_crossings() as f64 / self.len() as f64
+                }
+            }
+        }
+    };
+}
+
+impl_zero_crossings!(f32);
+impl_zero_crossings!(f64);
+
+/// 定义按梯形法计算采样曲线积分的契定。
+pub trait TrapezoidalIntegral {
+    /// 返回以 `dx` 为采样间隔时，采样点连成的折线与横轴之间的面积。
+    ///
+    /// 即 `dx * (x[0] / 2 + x[1] + ... + x[n - 2] + x[n - 1] / 2)`，样本少于两个时返回 `0`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{TrapezoidalIntegral};
+    ///
+    /// assert_eq!([2.0f32, 2.0, 2.0].trapezoidal_integral(1.0), 4.0);
+    /// assert_eq!([0.0f64, 1.0, 2.0].trapezoidal_integral(0.5), 1.0);
+    /// ```
+    fn trapezoidal_integral(&self, dx: f64) -> f64;
+}
+
+macro_rules! impl_trapezoidal_integral {
+    ($Type:ty) => {
+        impl TrapezoidalIntegral for [$Type] {
+            fn trapezoidal_integral(&self, dx: f64) -> f64 {
+                let sum: f64 = self
+                    .windows(2)
+                    .map(|w| (w[0] as f64 + w[1] as f64) * 0.5)
+                    .sum();
+                sum * dx
+            }
+        }
+    };
+}
+
+impl_trapezoidal_integral!(f32);
+impl_trapezoidal_integral!(f64);
+
+/// 定义在有序数组中查找插入位置的契定。
+pub trait SortedInsertIndex<T> {
+    /// 返回有序数组中第一个不小于 `value` 的元素索引，`value` 大于所有元素时返回长度。
+    ///
+    /// 在该位置插入 `value` 可保持数组有序。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{SortedInsertIndex};
+    ///
+    /// assert_eq!([1, 3, 7, 9].sorted_insert_index(&5), 2);
+    /// assert_eq!([1, 3, 7, 9].sorted_insert_index(&7), 2);
+    /// assert_eq!([1, 3, 7, 9].sorted_insert_index(&10), 4);
+    /// ```
+    fn sorted_insert_index(&self, value: &T) -> usize;
+}
+
+impl<T: Ord> SortedInsertIndex<T> for [T] {
+    fn sorted_insert_index(&self, value: &T) -> usize {
+        let (mut lo, mut hi) = (0, self.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self[mid] < *value {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+}
+
+/// 定义一阶 IIR 低通平滑的契定。
+#[cfg(feature = "alloc")]
+pub trait SmoothIir<T> {
+    /// 返回按 `y[i] = alpha * x[i] + (1 - alpha) * y[i - 1]` 平滑后的序列，其中 `y[0] = x[0]`。
+    ///
+    /// `alpha` 会被限制在 `[0, 1]` 的范围之内。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{SmoothIir};
+    ///
+    /// assert_eq!([0.0f32, 1.0, 1.0, 1.0].smooth_iir(0.5), [0.0, 0.5, 0.75, 0.875]);
+    /// ```
+    fn smooth_iir(&self, alpha: f32) -> Vec<T>;
+}
+
+macro_rules! impl_smooth_iir {
+    ($Type:ty) => {
+        #[cfg(feature = "alloc")]
+        impl SmoothIir<$Type> for [$Type] {
+            fn smooth_iir(&self, alpha: f32) -> Vec<$Type> {
+                let alpha = alpha.clamped(0.0, 1.0) as $Type;
+                let mut out = Vec::with_capacity(self.len());
+                let mut y = match self.first() {
+                    Some(&x) => x,
+                    None => return out,
+                };
+                for &x in self {
+                    y = alpha * x + (1.0 - alpha) * y;
+                    out.push(y);
+                }
+                out
+            }
+        }
+    };
+}
+
+impl_smooth_iir!(f32);
+impl_smooth_iir!(f64);
+
+/// 定义计算数组相邻元素差分的契定。
+#[cfg(feature = "alloc")]
+pub trait Diff<T> {
+    /// 返回相邻元素的差 `self[i + 1] - self[i]`，长度为 `len - 1`。
+    ///
+    /// 整数类型按回绕减法计算，以便与前缀和互为逆运算；数组为空或只有一个元素时返回空序列。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Diff};
+    ///
+    /// assert_eq!([1, 4, 9, 16].diff(), [3, 5, 7]);
+    /// assert_eq!([0u8, 255, 1].diff(), [255, 2]);
+    /// assert!([1.0f32].diff().is_empty());
+    /// ```
+    fn diff(&self) -> Vec<T>;
+}
+
+macro_rules! impl_diff {
+    ($Type:ty) => {
+        #[cfg(feature = "alloc")]
+        impl Diff<$Type> for [$Type] {
+            fn diff(&self) -> Vec<$Type> {
+                self.windows(2).map(|w| w[1].wrapping_sub(w[0])).collect()
+            }
+        }
+    };
+}
+
+macro_rules! impl_diff_float {
+    ($Type:ty) => {
+        #[cfg(feature = "alloc")]
+        impl Diff<$Type> for [$Type] {
+            fn diff(&self) -> Vec<$Type> {
+                self.windows(2).map(|w| w[1] - w[0]).collect()
+            }
+        }
+    };
+}
+
+impl_diff!(i8);
+impl_diff!(i16);
+impl_diff!(i32);
+impl_diff!(i64);
+impl_diff!(isize);
+impl_diff!(u8);
+impl_diff!(u16);
+impl_diff!(u32);
+impl_diff!(u64);
+impl_diff!(usize);
+impl_diff_float!(f32);
+impl_diff_float!(f64);
+
+/// 定义以类型化引用查看字节数组片段的契定。
+pub trait ViewAt {
+    /// 返回位于 `offset` 处的 `&U` 引用。
+    ///
+    /// 当该位置的地址未按 `U` 的要求对齐或剩余空间不足 `size_of::<U>()` 时返回 `None`。
+    ///
+    /// # Safety
+    ///
+    /// `U` 必须是任意字节组合均为合法值的 POD 类型（如仅由整数组成的 `#[repr(C)]` 结构）。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{ViewAt};
+    ///
+    /// let words = [0x0403_0201u32, 0x0807_0605];
+    /// let bytes = unsafe { std::slice::from_raw_parts(words.as_ptr() as *const u8, 8) };
+    /// assert_eq!(unsafe { bytes.view_at::<u32>(4) }, Some(&0x0807_0605));
+    /// assert_eq!(unsafe { bytes.view_at::<u32>(2) }, None);
+    /// assert_eq!(unsafe { bytes.view_at::<u64>(4) }, None);
+    /// ```
+    unsafe fn view_at<U>(&self, offset: usize) -> Option<&U>;
+}
+
+impl ViewAt for [u8] {
+    unsafe fn view_at<U>(&self, offset: usize) -> Option<&U> {
+        let end = offset.checked_add(core::mem::size_of::<U>())?;
+        if end > self.len() {
+            return None;
+        }
+        let ptr = self.as_ptr().add(offset);
+        if (ptr as usize) & (core::mem::align_of::<U>() - 1) != 0 {
+            return None;
+        }
+        Some(&*(ptr as *const U))
+    }
+}
+
+/// 返回以 `center` 为中心、半径为 `radius` 的窗口 `[start, end)`，并限制在 `[0, len)` 之内。
+///
+/// 靠近边界时窗口会被截断为非对称窗口，`radius` 超过 `len` 时窗口覆盖整个范围。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{centered_window};
+///
+/// assert_eq!(centered_window(0, 2, 10), (0, 3));
+/// assert_eq!(centered_window(5, 2, 10), (3, 8));
+/// assert_eq!(centered_window(9, 2, 10), (7, 10));
+/// assert_eq!(centered_window(5, 20, 10), (0, 10));
+/// ```
+pub fn centered_window(center: usize, radius: usize, len: usize) -> (usize, usize) {
+    let start = center.saturating_sub(radius).min(len);
+    let end = center.saturating_add(radius).saturating_add(1).min(len);
+    (start, end)
+}
+
+/// 将超出 `[0, len)` 的索引以边界为镜面反射回范围之内，边界元素本身不重复。
+///
+/// 例如长度为 `4` 时索引 `-1` 映射为 `1`，索引 `4` 映射为 `2`；超出多个长度的索引会被反复反射。
+/// `len` 为 `0` 或 `1` 时返回 `0`。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{reflect_index};
+///
+/// assert_eq!(reflect_index(-1, 4), 1);
+/// assert_eq!(reflect_index(4, 4), 2);
+/// assert_eq!(reflect_index(2, 4), 2);
+/// assert_eq!(reflect_index(-7, 4), 1);
+/// ```
+pub fn reflect_index(index: isize, len: usize) -> usize {
+    if len <= 1 {
+        return 0;
+    }
+    let period = 2 * (len as i128 - 1);
+    let m = (index as i128).rem_euclid(period);
+    if m < len as i128 {
+        m as usize
+    } else {
+        (period - m) as usize
+    }
+}
+
+/// 定义按最近邻重采样数组的契定。
+#[cfg(feature = "alloc")]
+pub trait ResampleNearest<T> {
+    /// 按最近邻采样返回长度为 `target_len` 的新数组。
+    ///
+    /// 第 `i` 个元素取自其对应区间中心 `(2 * i + 1) * len / (2 * target_len)` 处的元素，
+    /// 因此首尾元素均能被采样到，且结果不会随缩放比例向起始端偏移。
+    ///
+    /// 可用于降采样或升采样（重复元素），`target_len` 为 `0` 或数组为空时返回空数组。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{ResampleNearest};
+    ///
+    /// assert_eq!([0, 1, 2, 3, 4, 5, 6, 7].resample_nearest(4), [1, 3, 5, 7]);
+    /// assert_eq!([1, 2].resample_nearest(4), [1, 1, 2, 2]);
+    /// ```
+    fn resample_nearest(&self, target_len: usize) -> Vec<T>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> ResampleNearest<T> for [T] {
+    fn resample_nearest(&self, target_len: usize) -> Vec<T> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        (0..target_len)
+            .map(|i| {
+                let center = (2 * i as u128 + 1) * self.len() as u128 / (2 * target_len as u128);
+                self[center as usize].clone()
+            })
+            .collect()
+    }
+}
+
+/// 定义按比较函数判断数组是否有序的契定。
+pub trait IsSortedBy<T> {
+    /// 当每对相邻元素按 `f` 比较均不为 `Ordering::Greater` 时返回 `true`。
+    ///
+    /// 空数组及单元素数组总是有序的。为避免与标准库 `[T]::is_sorted_by` 重名，
+    /// 该方法命名为 `is_sorted_by_cmp`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{IsSortedBy};
+    ///
+    /// assert!([1, 2, 2, 9].is_sorted_by_cmp(|a, b| a.cmp(b)));
+    /// assert!([9, 2, 2, 1].is_sorted_by_cmp(|a, b| b.cmp(a)));
+    /// assert!(![1, 3, 2].is_sorted_by_cmp(|a, b| a.cmp(b)));
+    /// ```
+    fn is_sorted_by_cmp<F>(&self, f: F) -> bool
+    where
+        F: Fn(&T, &T) -> core::cmp::Ordering;
+}
+
+impl<T> IsSortedBy<T> for [T] {
+    fn is_sorted_by_cmp<F>(&self, f: F) -> bool
+    where
+        F: Fn(&T, &T) -> core::cmp::Ordering,
+    {
+        self.windows(2)
+            .all(|w| f(&w[0], &w[1]) != core::cmp::Ordering::Greater)
+    }
+}
+
+/// 定义计算按通道数对齐的数组主体长度的契定。
+pub trait AlignedBodyLen {
+    /// 返回不超过数组长度的 `lanes` 的最大倍数，用于划分向量化主体与标量尾部。
+    ///
+    /// `lanes` 为 `0` 时返回 `0`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{AlignedBodyLen};
+    ///
+    /// assert_eq!([0.0f32; 19].aligned_body_len(8), 16);
+    /// assert_eq!([0.0f32; 19].aligned_body_len(0), 0);
+    /// ```
+    fn aligned_body_len(&self, lanes: usize) -> usize;
+}
+
+impl<T> AlignedBodyLen for [T] {
+    fn aligned_body_len(&self, lanes: usize) -> usize {
+        if lanes == 0 {
+            return 0;
+        }
+        self.len().align_downwards(lanes)
+    }
+}
+
+/// 字节窗口上的多项式滚动哈希。
+///
+/// 哈希值为窗口内字节 `b[0..n]` 的 `Σ b[i] * BASE^(n-1-i)`（按 `u64` 回绕运算），
+/// 可用于内容定义分块等场景。
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{RollingHash};
+///
+/// let mut rh = RollingHash::new(4);
+/// for &b in b"hello world" {
+///     rh.push(b);
+/// }
+/// assert_eq!(rh.hash(), RollingHash::compute(b"orld"));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct RollingHash {
+    buf: Vec<u8>,
+    pos: usize,
+    filled: bool,
+    hash: u64,
+    pow: u64,
+}
+
+#[cfg(feature = "alloc")]
+impl RollingHash {
+    const BASE: u64 = 0x0000_0100_0000_01B3;
+
+    /// 创建一个窗口大小为 `window` 字节的滚动哈希，`window` 为 `0` 时按 `1` 处理。
+    pub fn new(window: usize) -> Self {
+        let window = window.max(1);
+        let pow = (1..window).fold(1u64, |p, _| p.wrapping_mul(Self::BASE));
+        Self {
+            buf: alloc::vec![0; window],
+            pos: 0,
+            filled: false,
+            hash: 0,
+            pow,
+        }
+    }
+
+    /// 计算 `bytes` 整体的哈希值，与窗口恰好包含 `bytes` 时的 [hash] 一致。
+    ///
+    /// [hash]: #method.hash
+    pub fn compute(bytes: &[u8]) -> u64 {
+        bytes.iter().fold(0u64, |h, &b| {
+            h.wrapping_mul(Self::BASE).wrapping_add(b as u64)
+        })
+    }
+
+    /// 将一个字节移入窗口（必要时移出最旧的字节），返回新的哈希值。
+    pub fn push(&mut self, byte: u8) -> u64 {
+        if self.filled {
+            let out = self.buf[self.pos] as u64;
+            self.hash = self.hash.wrapping_sub(out.wrapping_mul(self.pow));
+        }
+        self.hash = self.hash.wrapping_mul(Self::BASE).wrapping_add(byte as u64);
+        self.buf[self.pos] = byte;
+        self.pos += 1;
+        if self.pos == self.buf.len() {
+            self.pos = 0;
+            self.filled = true;
         }
+        self.hash
+    }
+
+    /// 返回当前窗口的哈希值。
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// 返回窗口大小。
+    pub fn window(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// 当窗口已填满且哈希值与 `mask` 相与为 `0` 时返回 `true`，可作为分块边界条件。
+    pub fn is_boundary(&self, mask: u64) -> bool {
+        self.filled && self.hash & mask == 0
     }
 }
 
-trait CopyFromSliceFlex<T: Copy> {
-    /// Copies all elements from `src` into `self`, using a memcpy.
+/// 定义计算补码校验字节的契定。
+pub trait ChecksumComplement {
+    /// 返回追加到数组末尾后可使全部字节之和模 256 为 `0` 的校验字节。
     ///
-    /// The length of `src` could be different to `self`.
-    fn copy_from_slice_flex(&mut self, src: &[T]);
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{ChecksumComplement};
+    ///
+    /// // Intel HEX 记录 `:0300300002337A1E`。
+    /// assert_eq!([0x03, 0x00, 0x30, 0x00, 0x02, 0x33, 0x7A].checksum_complement(), 0x1E);
+    /// ```
+    fn checksum_complement(&self) -> u8;
 }
 
-impl<T: Copy> CopyFromSliceFlex<T> for [T] {
-    fn copy_from_slice_flex(&mut self, src: &[T]) {
-        let len = self.len().min(src.len());
+impl ChecksumComplement for [u8] {
+    fn checksum_complement(&self) -> u8 {
+        self.iter()
+            .fold(0u8, |acc, &b| acc.wrapping_add(b))
+            .wrapping_neg()
+    }
+}
+
+/// 定义将数组拆分为未对齐首尾及已对齐中段的契定。
+pub trait AlignToType<T> {
+    /// 将数组拆分为 `(首部, 中段, 尾部)`，其中中段按 `U` 对齐并重新解释为 `&[U]`。
+    ///
+    /// 等价于标准库的 `slice::align_to`，首部与尾部需要单独以 `T` 处理。
+    ///
+    /// # Safety
+    ///
+    /// `U` 必须是任意字节组合均为合法值的 POD 类型（如整数或仅由整数组成的 `#[repr(C)]` 结构），
+    /// `T` 同样应为 POD 类型（如 `u8`）。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{AlignToType};
+    ///
+    /// let bytes = [0u8; 37];
+    /// let (head, mid, tail) = unsafe { bytes[1..].align_to_type::<u32>() };
+    /// assert_eq!(head.len() + mid.len() * 4 + tail.len(), 36);
+    /// assert_eq!(mid.as_ptr() as usize % std::mem::align_of::<u32>(), 0);
+    /// ```
+    unsafe fn align_to_type<U>(&self) -> (&[T], &[U], &[T]);
+}
+
+impl<T> AlignToType<T> for [T] {
+    unsafe fn align_to_type<U>(&self) -> (&[T], &[U], &[T]) {
+        self.align_to::<U>()
+    }
+}
+
+/// 定义将数组整体视作字节数组的契定。
+pub trait AsBytesSlice {
+    /// 将整个数组重新解释为字节数组，长度为 `len * size_of::<T>()`。
+    ///
+    /// 元素类型需实现 [AsBytes]，以保证数组中不含填充字节；
+    /// 自定义的无填充 `#[repr(C)]` 结构可通过 [impl_as_bytes] 实现该契定。
+    ///
+    /// [AsBytes]: trait.AsBytes.html
+    /// [impl_as_bytes]: macro.impl_as_bytes.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{AsBytesSlice};
+    ///
+    /// let a = [0x0102u16, 0x0304];
+    /// assert_eq!(a.as_bytes_slice().len(), 4);
+    /// assert_eq!(&a.as_bytes_slice()[..2], &0x0102u16.to_ne_bytes());
+    /// ```
+    fn as_bytes_slice(&self) -> &[u8];
+}
+
+impl<T: AsBytes> AsBytesSlice for [T] {
+    fn as_bytes_slice(&self) -> &[u8] {
         unsafe {
-            std::ptr::copy_nonoverlapping(src.as_ptr(), self.as_mut_ptr(), len);
+            core::slice::from_raw_parts(self.as_ptr() as *const u8, core::mem::size_of_val(self))
+        }
+    }
+}
+
+/// 定义计算滑动窗口最大值的契定。
+#[cfg(feature = "alloc")]
+pub trait SlidingMax<T> {
+    /// 返回每个长度为 `window` 的窗口内的最大值。
+    ///
+    /// 使用单调队列实现，时间复杂度为 `O(n)`；仅对窗口完整的位置输出结果，
+    /// `window` 为 `0` 或大于长度时返回空序列。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{SlidingMax};
+    ///
+    /// assert_eq!([1, 3, 2, 5, 4].sliding_max(3), [3, 5, 5]);
+    /// assert!([1, 2, 3].sliding_max(0).is_empty());
+    /// assert!([1, 2, 3].sliding_max(4).is_empty());
+    /// ```
+    fn sliding_max(&self, window: usize) -> Vec<T>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: PartialOrd + Copy> SlidingMax<T> for [T] {
+    fn sliding_max(&self, window: usize) -> Vec<T> {
+        if window == 0 || window > self.len() {
+            return Vec::new();
+        }
+        let mut out = Vec::with_capacity(self.len() - window + 1);
+        // 队列中保存索引，对应的值自队首至队尾单调递减。
+        let mut deque: VecDeque<usize> = VecDeque::with_capacity(window);
+        for (i, &x) in self.iter().enumerate() {
+            while let Some(&j) = deque.back() {
+                if self[j] > x {
+                    break;
+                }
+                deque.pop_back();
+            }
+            deque.push_back(i);
+            if deque[0] + window <= i {
+                deque.pop_front();
+            }
+            if i + 1 >= window {
+                out.push(self[deque[0]]);
+            }
         }
+        out
     }
 }
 
+/// 定义统计数组中不同元素个数的契定。
+///
+/// 需要启用 `std` 特性。
+#[cfg(feature = "std")]
+pub trait CountDistinct {
+    /// 返回数组中互不相等的元素个数。
+    ///
+    /// 仅要求元素可哈希，不要求可排序。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{CountDistinct};
+    ///
+    /// assert_eq!([1, 2, 2, 3, 3, 3].count_distinct(), 3);
+    /// assert_eq!(([] as [u8; 0]).count_distinct(), 0);
+    /// ```
+    fn count_distinct(&self) -> usize;
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq + core::hash::Hash> CountDistinct for [T] {
+    fn count_distinct(&self) -> usize {
+        self.iter().collect::<std::collections::HashSet<_>>().len()
+    }
+}
+
+/// 定义按多数表决对数组降采样的契定。
+///
+/// 需要启用 `std` 特性。
+#[cfg(feature = "std")]
+pub trait DownsampleMajority<T> {
+    /// 将数组按每 `factor` 个元素分组，返回每组中出现次数最多的元素。
+    ///
+    /// 出现次数相同时取组内最先出现者；末尾不足 `factor` 个元素的分组按其已有元素表决，
+    /// `factor` 为 `0` 时返回空序列。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{DownsampleMajority};
+    ///
+    /// assert_eq!([1, 1, 2, 3, 3, 3, 4].downsample_majority(3), [1, 3, 4]);
+    /// assert_eq!(["a", "b"].downsample_majority(2), ["a"]);
+    /// ```
+    fn downsample_majority(&self, factor: usize) -> Vec<T>;
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq + core::hash::Hash + Clone> DownsampleMajority<T> for [T] {
+    fn downsample_majority(&self, factor: usize) -> Vec<T> {
+        if factor == 0 {
+            return Vec::new();
+        }
+        let mut counts = std::collections::HashMap::new();
+        self.chunks(factor)
+            .map(|chunk| {
+                counts.clear();
+                for x in chunk {
+                    *counts.entry(x).or_insert(0usize) += 1;
+                }
+                let max = counts.values().copied().max().unwrap_or(0);
+                chunk.iter().find(|x| counts[x] == max).cloned().unwrap()
+            })
+            .collect()
+    }
+}
+
+/// 定义按权重选取索引的契定。
+pub trait WeightedIndex {
+    /// 将数组元素视为权重，按累积分布返回 `r` 所落入区间的索引。
+    ///
+    /// `r` 应处于 `[0, 1)` 之内，超出时分别按首个与末个权重为正的元素处理；
+    /// 负权重视为 `0`，数组为空或权重之和不为正时返回 `None`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{WeightedIndex};
+    ///
+    /// assert_eq!([1, 1, 2].weighted_index(0.75), Some(2));
+    /// assert_eq!([1, 1, 2].weighted_index(0.3), Some(1));
+    /// assert_eq!([0.0f32, 0.0].weighted_index(0.5), None);
+    /// ```
+    fn weighted_index(&self, r: f64) -> Option<usize>;
+}
+
+macro_rules! impl_weighted_index {
+    ($Type:ty) => {
+        impl WeightedIndex for [$Type] {
+            fn weighted_index(&self, r: f64) -> Option<usize> {
+                let weight = |&w: &$Type| if w > 0 as $Type { w as f64 } else { 0.0 };
+                let total: f64 = self.iter().map(weight).sum();
+                if total <= 0.0 {
+                    return None;
+                }
+                let target = r.clamped(0.0, 1.0) * total;
+                let mut cum = 0.0;
+                let mut last = None;
+                for (i, w) in self.iter().map(weight).enumerate() {
+                    if w > 0.0 {
+                        cum += w;
+                        last = Some(i);
+                        if target < cum {
+                            break;
+                        }
+                    }
+                }
+                last
+            }
+        }
+    };
+}
+
+impl_weighted_index!(i8);
+impl_weighted_index!(i16);
+impl_weighted_index!(i32);
+impl_weighted_index!(i64);
+impl_weighted_index!(isize);
+impl_weighted_index!(u8);
+impl_weighted_index!(u16);
+impl_weighted_index!(u32);
+impl_weighted_index!(u64);
+impl_weighted_index!(usize);
+impl_weighted_index!(f32);
+impl_weighted_index!(f64);
+
+/// 定义合并重叠区间的契定。
+#[cfg(feature = "alloc")]
+pub trait MergeRanges<T> {
+    /// 将 `(起点, 终点)` 闭区间按起点排序，并合并相互重叠或首尾相邻的区间。
+    ///
+    /// 起点大于终点的区间会先交换两端；数组为空时返回空序列。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{MergeRanges};
+    ///
+    /// assert_eq!([(1, 3), (2, 5), (7, 8)].merge_ranges(), [(1, 5), (7, 8)]);
+    /// assert_eq!([(4, 5), (1, 3)].merge_ranges(), [(1, 5)]);
+    /// ```
+    fn merge_ranges(&self) -> Vec<(T, T)>;
+}
+
+macro_rules! impl_merge_ranges {
+    ($Type:ty) => {
+        #[cfg(feature = "alloc")]
+        impl MergeRanges<$Type> for [($Type, $Type)] {
+            fn merge_ranges(&self) -> Vec<($Type, $Type)> {
+                let mut ranges: Vec<($Type, $Type)> =
+                    self.iter().map(|&(a, b)| (a.min(b), a.max(b))).collect();
+                ranges.sort_unstable();
+                let mut out: Vec<($Type, $Type)> = Vec::with_capacity(ranges.len());
+                for (start, end) in ranges {
+                    match out.last_mut() {
+                        Some(last) if start <= last.1.saturating_add(1) => {
+                            last.1 = last.1.max(end);
+                        }
+                        _ => out.push((start, end)),
+                    }
+                }
+                out
+            }
+        }
+    };
+}
+
+impl_merge_ranges!(i8);
+impl_merge_ranges!(i16);
+impl_merge_ranges!(i32);
+impl_merge_ranges!(i64);
+impl_merge_ranges!(isize);
+impl_merge_ranges!(u8);
+impl_merge_ranges!(u16);
+impl_merge_ranges!(u32);
+impl_merge_ranges!(u64);
+impl_merge_ranges!(usize);
+
+/// 定义转置矩阵存储顺序的契定。
+pub trait TransposeInto<T> {
+    /// 将按行优先存储的 `rows × cols` 矩阵转置后写入 `dst`，结果为按行优先存储的 `cols × rows` 矩阵。
+    ///
+    /// 同一份数据在行优先与列优先两种存储顺序之间的转换即为一次转置。
+    ///
+    /// # Panics
+    ///
+    /// `self.len()` 不等于 `rows * cols` 或 `dst` 长度不足时 panic。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{TransposeInto};
+    ///
+    /// let m = [1, 2, 3, 4, 5, 6];
+    /// let mut t = [0; 6];
+    /// m.transpose_into(2, 3, &mut t);
+    /// assert_eq!(t, [1, 4, 2, 5, 3, 6]);
+    /// ```
+    fn transpose_into(&self, rows: usize, cols: usize, dst: &mut [T]);
+}
+
+impl<T: Copy> TransposeInto<T> for [T] {
+    fn transpose_into(&self, rows: usize, cols: usize, dst: &mut [T]) {
+        let len = rows
+            .checked_mul(cols)
+            .expect("transpose_into: rows * cols overflows");
+        assert_eq!(self.len(), len, "transpose_into: source length mismatch");
+        assert!(dst.len() >= len, "transpose_into: destination too small");
+        for r in 0..rows {
+            for c in 0..cols {
+                dst[c * rows + r] = self[r * cols + c];
+            }
+        }
+    }
+}
+
+/// 定义计算数组质心（按元素值加权的平均索引）的契定。
+pub trait Centroid {
+    /// 返回以元素值为权重的索引加权平均值，即 `sum(i * x[i]) / sum(x[i])`。
+    ///
+    /// 数组为空或权重之和为 `0` 时返回 `None`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pavo_traits::{Centroid};
+    ///
+    /// assert_eq!([1, 2, 1].centroid(), Some(1.0));
+    /// assert_eq!([0.0f32, 1.0, 3.0].centroid(), Some(1.75));
+    /// assert_eq!([0u8, 0].centroid(), None);
+    /// ```
+    fn centroid(&self) -> Option<f64>;
+}
+
+macro_rules! impl_centroid {
+    ($Type:ty) => {
+        impl Centroid for [$Type] {
+            fn centroid(&self) -> Option<f64> {
+                let (moment, total) = self
+                    .iter()
+                    .enumerate()
+                    .fold((0.0f64, 0.0f64), |(m, t), (i, &x)| {
+                        (m + i as f64 * x as f64, t + x as f64)
+                    });
+                if total == 0.0 {
+                    None
+                } else {
+                    Some(moment / total)
+                }
+            }
+        }
+    };
+}
+
+impl_centroid!(i8);
+impl_centroid!(i16);
+impl_centroid!(i32);
+impl_centroid!(i64);
+impl_centroid!(isize);
+impl_centroid!(u8);
+impl_centroid!(u16);
+impl_centroid!(u32);
+impl_centroid!(u64);
+impl_centroid!(usize);
+impl_centroid!(f32);
+impl_centroid!(f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,4 +1567,605 @@ mod tests {
         a4.copy_from_slice_flex(&[5, 6, 7, 8, 9]);
         assert_eq!(a4, [5, 6, 7, 8]);
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_moving_average() {
+        assert_eq!([1, 2, 3, 4, 5].moving_average(3), [2.0, 3.0, 4.0]);
+        assert_eq!([1u8, 2, 3].moving_average(1), [1.0, 2.0, 3.0]);
+        assert_eq!([1.0f32, 2.0, 3.0].moving_average(3), [2.0]);
+        assert_eq!([-4i8, 4, -4, 4].moving_average(2), [0.0, 0.0, 0.0]);
+        assert!([1, 2, 3].moving_average(0).is_empty());
+        assert!([1, 2, 3].moving_average(4).is_empty());
+        assert!(([] as [u32; 0]).moving_average(1).is_empty());
+    }
+
+    #[test]
+    fn test_clamp_between() {
+        let mut a = [5, 5, 5];
+        a.clamp_between(&[0, 6, 0], &[4, 10, 10]);
+        assert_eq!(a, [4, 6, 5]);
+        let mut a = [5, 5, 5];
+        a.clamp_between(&[6, 6], &[10, 10, 10]);
+        assert_eq!(a, [6, 6, 5]);
+        let mut a = [0.5f32, -1.0];
+        a.clamp_between(&[0.0, 0.0], &[0.25, 1.0]);
+        assert_eq!(a, [0.25, 0.0]);
+        let mut a = [1, 2];
+        a.clamp_between(&[], &[]);
+        assert_eq!(a, [1, 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_reorder_channels() {
+        let lr = [1, 2, 3, 4, 5, 6];
+        assert_eq!(
+            lr.reorder_channels(&[1, 0], 3),
+            Some(vec![2, 1, 4, 3, 6, 5])
+        );
+        assert_eq!(lr.reorder_channels(&[0, 1], 3), Some(lr.to_vec()));
+        assert_eq!(
+            lr.reorder_channels(&[2, 0, 1], 2),
+            Some(vec![3, 1, 2, 6, 4, 5])
+        );
+        assert_eq!(lr.reorder_channels(&[1, 0], 2), None);
+        assert_eq!(lr.reorder_channels(&[2, 0], 3), None);
+        assert_eq!(lr.reorder_channels(&[], 0), None);
+        assert_eq!(([] as [u8; 0]).reorder_channels(&[], 0), Some(vec![]));
+    }
+
+    #[test]
+    fn test_hamming_distance_slice() {
+        assert_eq!(
+            [0b1010u8, 0xFF].hamming_distance_slice(&[0b0110, 0x00]),
+            Some(10)
+        );
+        assert_eq!([1u64, 2, 3].hamming_distance_slice(&[1, 2, 3]), Some(0));
+        assert_eq!(([] as [u32; 0]).hamming_distance_slice(&[]), Some(0));
+        assert_eq!([0u16].hamming_distance_slice(&[0, 0]), None);
+    }
+
+    #[test]
+    fn test_leading_trailing_count() {
+        let a = [0, 0, 5, 0];
+        assert_eq!(a.leading_count(&0), 2);
+        assert_eq!(a.trailing_count(&0), 1);
+        assert_eq!(a.leading_count(&5), 0);
+        assert_eq!([7u8; 4].leading_count(&7), 4);
+        assert_eq!([7u8; 4].trailing_count(&7), 4);
+        assert_eq!(([] as [u8; 0]).leading_count(&0), 0);
+        assert_eq!(([] as [u8; 0]).trailing_count(&0), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_split_into_parts() {
+        let a: Vec<usize> = (0..10).collect();
+        let parts = a.split_into_parts(3);
+        assert_eq!(parts, [&a[0..4], &a[4..7], &a[7..10]]);
+        assert_eq!(parts.concat(), a);
+        let parts = a.split_into_parts(5);
+        assert!(parts.iter().all(|p| p.len() == 2));
+        let parts = a.split_into_parts(12);
+        assert_eq!(parts.len(), 12);
+        assert_eq!(parts.concat(), a);
+        assert!(parts[10].is_empty() && parts[11].is_empty());
+        assert!(a.split_into_parts(0).is_empty());
+        assert_eq!(([] as [u8; 0]).split_into_parts(2), [&[] as &[u8], &[]]);
+    }
+
+    #[test]
+    fn test_variance() {
+        let a = [2.0f64, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert!((a.variance().unwrap() - 32.0 / 7.0).abs() < 1e-12);
+        #[cfg(feature = "std")]
+        assert!((a.std_dev().unwrap() - (32.0f64 / 7.0).sqrt()).abs() < 1e-12);
+        let b = [1e9f64 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0];
+        assert!((b.variance().unwrap() - 30.0).abs() < 1e-6);
+        assert_eq!([3.0f32, 3.0].variance(), Some(0.0));
+        assert_eq!([1.0f64].variance(), None);
+        #[cfg(feature = "std")]
+        assert_eq!(([] as [f64; 0]).std_dev(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_winsorize() {
+        let mut a = [100, 1, 2, 3, 4, 5, 6, 7, 8, -100];
+        assert!(a.winsorize(10.0, 90.0));
+        assert_eq!(a, [8, 1, 2, 3, 4, 5, 6, 7, 8, 1]);
+        let mut b = [0.5f64, 1e9, 0.25, -1e9, 0.75];
+        assert!(b.winsorize(25.0, 75.0));
+        assert_eq!(b, [0.5, 0.75, 0.25, 0.25, 0.75]);
+        let mut c = [3, 1, 2];
+        assert!(c.winsorize(0.0, 100.0));
+        assert_eq!(c, [3, 1, 2]);
+        assert!(!c.winsorize(-1.0, 50.0));
+        assert!(!c.winsorize(50.0, 101.0));
+        assert!(!c.winsorize(50.0, 50.0));
+        assert!(!c.winsorize(f64::NAN, 50.0));
+        assert!(([] as [u8; 0]).winsorize(10.0, 90.0));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_pack_bits() {
+        let bits = [
+            true, false, true, true, false, false, false, false, false, true,
+        ];
+        let mut dst = [0xFFu8; 2];
+        assert_eq!(bits.pack_bits_into(&mut dst), 2);
+        assert_eq!(dst, [0b0000_1101, 0b0000_0010]);
+        assert_eq!(unpack_bits(&dst, bits.len()), bits);
+        let mut small = [0u8; 1];
+        assert_eq!(bits.pack_bits_into(&mut small), 1);
+        assert_eq!(small, [0b0000_1101]);
+        assert_eq!(unpack_bits(&small, 10), &bits[..8]);
+        assert_eq!([].pack_bits_into(&mut dst), 0);
+        assert!(unpack_bits(&[], 4).is_empty());
+    }
+
+    #[test]
+    fn test_dot() {
+        assert_eq!([1, 2, 3].dot(&[4, 5, 6]), 32);
+        assert_eq!([1, 2, 3].dot(&[4, 5]), 14);
+        assert_eq!([1, 2, 3].dot_strict(&[4, 5]), None);
+        assert_eq!([-1i8, 2].dot_strict(&[3, 4]), Some(5));
+        assert_eq!([100u8, 100].dot(&[100, 100]), u8::MAX);
+        assert_eq!([i16::MIN, -1].dot(&[2, 1]), i16::MIN);
+        assert_eq!([0.5f32, 2.0].dot(&[4.0, 0.25]), 2.5);
+        assert_eq!(([] as [f64; 0]).dot(&[]), 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_find_peaks() {
+        let env = [0u8, 1, 8, 2, 1, 1, 6, 9, 3, 0];
+        assert_eq!(env.find_peaks(3), [2, 7]);
+        assert_eq!(env.find_peaks(7), Vec::<usize>::new());
+        assert_eq!([9, 1, 9].find_peaks(0), [] as [usize; 0]);
+        assert_eq!([1, 5, 5, 1].find_peaks(0), [] as [usize; 0]);
+        assert_eq!([0.0f32, 0.5, 0.25].find_peaks(0.1), [1]);
+        assert!(([] as [i32; 0]).find_peaks(0).is_empty());
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(b"123456789".crc32(), 0xCBF4_3926);
+        assert_eq!(b"".crc32(), 0);
+        assert_eq!(b"a".crc32(), 0xE8B7_BE43);
+        assert_eq!(
+            b"The quick brown fox jumps over the lazy dog".crc32(),
+            0x414F_A339
+        );
+    }
+
+    #[test]
+    fn test_zero_crossings() {
+        assert_eq!([1.0f32, -1.0, 1.0, -1.0].zero_crossings(), 3);
+        assert_eq!([1.0f64, 0.0, 0.0, -1.0, 0.0, 2.0].zero_crossings(), 2);
+        assert_eq!([0.0f64, -1.0, -2.0].zero_crossings(), 0);
+        assert_eq!([1.0f32, f32::NAN, -1.0].zero_crossings(), 1);
+        let sine: Vec<f64> = (0..100).map(|i| (i as f64 * 0.5 + 0.1).sin()).collect();
+        assert_eq!(sine.zero_crossings(), 15);
+        assert_eq!([1.0f32, -1.0, 1.0, -1.0].zero_crossing_rate(), 0.75);
+        assert_eq!(([] as [f32; 0]).zero_crossing_rate(), 0.0);
+    }
+
+    #[test]
+    fn test_sorted_insert_index() {
+        let a = [1, 3, 7, 9];
+        assert_eq!(a.sorted_insert_index(&5), 2);
+        assert_eq!(a.sorted_insert_index(&0), 0);
+        assert_eq!(a.sorted_insert_index(&1), 0);
+        assert_eq!(a.sorted_insert_index(&9), 3);
+        assert_eq!(a.sorted_insert_index(&10), 4);
+        assert_eq!([2, 2, 2].sorted_insert_index(&2), 0);
+        assert_eq!(([] as [u8; 0]).sorted_insert_index(&1), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_smooth_iir() {
+        let mut step = [1.0f64; 32];
+        step[0] = 0.0;
+        let y = step.smooth_iir(0.25);
+        for (n, &v) in y.iter().enumerate() {
+            assert!((v - (1.0 - 0.75f64.powi(n as i32))).abs() < 1e-12);
+        }
+        assert!(y.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!([3.0f32, 5.0].smooth_iir(0.0), [3.0, 3.0]);
+        assert_eq!([3.0f32, 5.0].smooth_iir(2.0), [3.0, 5.0]);
+        assert!(([] as [f32; 0]).smooth_iir(0.5).is_empty());
+    }
+
+    #[test]
+    fn test_view_at() {
+        #[repr(C, align(8))]
+        struct Buf([u8; 24]);
+
+        #[repr(C)]
+        #[derive(Debug, PartialEq)]
+        struct Header {
+            kind: u32,
+            len: u32,
+        }
+
+        let mut buf = Buf([0; 24]);
+        buf.0[8..12].copy_from_slice(&7u32.to_ne_bytes());
+        buf.0[12..16].copy_from_slice(&16u32.to_ne_bytes());
+        unsafe {
+            assert_eq!(
+                buf.0.view_at::<Header>(8),
+                Some(&Header { kind: 7, len: 16 })
+            );
+            assert_eq!(
+                buf.0.view_at::<Header>(16),
+                Some(&Header { kind: 0, len: 0 })
+            );
+            assert_eq!(buf.0.view_at::<Header>(9), None);
+            assert_eq!(buf.0.view_at::<Header>(20), None);
+            assert_eq!(buf.0.view_at::<Header>(usize::MAX), None);
+            assert_eq!(buf.0.view_at::<u8>(23), Some(&0));
+        }
+    }
+
+    #[test]
+    fn test_centered_window() {
+        assert_eq!(centered_window(0, 2, 10), (0, 3));
+        assert_eq!(centered_window(1, 2, 10), (0, 4));
+        assert_eq!(centered_window(5, 0, 10), (5, 6));
+        assert_eq!(centered_window(9, 2, 10), (7, 10));
+        assert_eq!(centered_window(5, usize::MAX, 10), (0, 10));
+        assert_eq!(centered_window(12, 3, 10), (9, 10));
+        assert_eq!(centered_window(20, 3, 10), (10, 10));
+        assert_eq!(centered_window(0, 2, 0), (0, 0));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_resample_nearest() {
+        let a = [0, 1, 2, 3, 4, 5, 6, 7];
+        assert_eq!(a.resample_nearest(4), [1, 3, 5, 7]);
+        assert_eq!(a.resample_nearest(8), a);
+        assert_eq!(a.resample_nearest(1), [4]);
+        assert_eq!([10, 20, 30].resample_nearest(2), [10, 30]);
+        assert_eq!([1, 2].resample_nearest(4), [1, 1, 2, 2]);
+        assert_eq!([1, 2, 3].resample_nearest(6), [1, 1, 2, 2, 3, 3]);
+        assert!(a.resample_nearest(0).is_empty());
+        assert!(([] as [u8; 0]).resample_nearest(4).is_empty());
+    }
+
+    #[test]
+    fn test_is_sorted_by() {
+        assert!([9, 7, 7, 1].is_sorted_by_cmp(|a, b| b.cmp(a)));
+        assert!(![9, 7, 8, 1].is_sorted_by_cmp(|a, b| b.cmp(a)));
+        assert!(["a", "bb", "ccc"].is_sorted_by_cmp(|a, b| a.len().cmp(&b.len())));
+        assert!([1.0f32, 0.5].is_sorted_by_cmp(|a, b| b.partial_cmp(a).unwrap()));
+        assert!([42].is_sorted_by_cmp(|a, b| a.cmp(b)));
+        assert!(([] as [u8; 0]).is_sorted_by_cmp(|a, b| a.cmp(b)));
+    }
+
+    #[test]
+    fn test_aligned_body_len() {
+        let a = [0.0f32; 19];
+        assert_eq!(a.aligned_body_len(8), 16);
+        assert_eq!(a[..16].aligned_body_len(8), 16);
+        assert_eq!(a[..7].aligned_body_len(8), 0);
+        assert_eq!(a.aligned_body_len(1), 19);
+        assert_eq!(a.aligned_body_len(0), 0);
+        assert_eq!(([] as [u8; 0]).aligned_body_len(4), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_rolling_hash() {
+        let data: Vec<u8> = (0..200u32).map(|i| (i * 31 % 251) as u8).collect();
+        let mut rh = RollingHash::new(16);
+        assert_eq!(rh.window(), 16);
+        for (i, &b) in data.iter().enumerate() {
+            let h = rh.push(b);
+            let start = (i + 1).saturating_sub(16);
+            assert_eq!(h, RollingHash::compute(&data[start..=i]));
+            assert_eq!(rh.is_boundary(0), i >= 15);
+        }
+        let boundaries = data
+            .iter()
+            .scan(RollingHash::new(8), |rh, &b| {
+                rh.push(b);
+                Some(rh.is_boundary(0x7))
+            })
+            .filter(|&b| b)
+            .count();
+        assert!(boundaries > 0 && boundaries < data.len());
+        let mut one = RollingHash::new(0);
+        one.push(1);
+        assert_eq!(one.push(2), 2);
+    }
+
+    #[test]
+    fn test_checksum_complement() {
+        let sum = |a: &[u8]| a.iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
+        for buf in [&b""[..], b"\x00", b"\xFF", b"hello world", &[0x80; 300]].iter() {
+            let mut v = buf.to_vec();
+            v.push(buf.checksum_complement());
+            assert_eq!(sum(&v), 0);
+        }
+        assert_eq!([].checksum_complement(), 0);
+        assert_eq!([1u8].checksum_complement(), 0xFF);
+    }
+
+    #[test]
+    fn test_align_to_type() {
+        let bytes: Vec<u8> = (0..64).collect();
+        for start in 0..8 {
+            let src = &bytes[start..];
+            let (head, mid, tail) = unsafe { src.align_to_type::<u64>() };
+            assert_eq!(mid.as_ptr() as usize % std::mem::align_of::<u64>(), 0);
+            assert!(head.len() < 8 && tail.len() < 8);
+            assert_eq!(head.len() + mid.len() * 8 + tail.len(), src.len());
+            let rebuilt: Vec<u8> = head
+                .iter()
+                .copied()
+                .chain(mid.iter().flat_map(|w| w.to_ne_bytes().to_vec()))
+                .chain(tail.iter().copied())
+                .collect();
+            assert_eq!(rebuilt, src);
+        }
+    }
+
+    #[test]
+    fn test_as_bytes_slice() {
+        use crate::convert::AsBytesMut;
+
+        #[repr(C)]
+        #[derive(Clone, Copy)]
+        struct Vertex {
+            x: f32,
+            y: f32,
+            z: f32,
+        }
+
+        crate::impl_as_bytes!(Vertex);
+
+        let vs = [
+            Vertex {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            },
+            Vertex {
+                x: -1.0,
+                y: 0.5,
+                z: 0.0,
+            },
+            Vertex {
+                x: 9.0,
+                y: 8.0,
+                z: 7.0,
+            },
+        ];
+        let bytes = vs.as_bytes_slice();
+        assert_eq!(bytes.len(), 3 * std::mem::size_of::<Vertex>());
+        for (v, chunk) in vs.iter().zip(bytes.chunks(std::mem::size_of::<Vertex>())) {
+            let fields = [v.x, v.y, v.z];
+            assert_eq!(chunk, fields.as_bytes_slice());
+            assert_eq!(&chunk[4..8], &v.y.to_ne_bytes());
+        }
+        assert!(([] as [u32; 0]).as_bytes_slice().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_sliding_max() {
+        assert_eq!([1, 3, 2, 5, 4].sliding_max(3), [3, 5, 5]);
+        assert_eq!([1, 3, 2, 5, 4].sliding_max(1), [1, 3, 2, 5, 4]);
+        assert_eq!([1, 3, 2, 5, 4].sliding_max(5), [5]);
+        assert_eq!([5, 4, 3, 2, 1].sliding_max(2), [5, 4, 3, 2]);
+        assert_eq!([0.5f32, -1.0, 0.25].sliding_max(2), [0.5, 0.25]);
+        assert!([1, 2, 3].sliding_max(0).is_empty());
+        assert!([1, 2, 3].sliding_max(4).is_empty());
+        let data: Vec<u32> = (0..100u32).map(|i| i * 37 % 101).collect();
+        let expected: Vec<u32> = data.windows(7).map(|w| *w.iter().max().unwrap()).collect();
+        assert_eq!(data.sliding_max(7), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_count_distinct() {
+        assert_eq!([1, 2, 2, 3, 3, 3].count_distinct(), 3);
+        assert_eq!([7u8; 16].count_distinct(), 1);
+        assert_eq!(([] as [u8; 0]).count_distinct(), 0);
+        assert_eq!(["a", "b", "a"].count_distinct(), 2);
+        #[derive(PartialEq, Eq, Hash)]
+        enum Color {
+            Red,
+            Green,
+        }
+        assert_eq!([Color::Red, Color::Green, Color::Red].count_distinct(), 2);
+    }
+
+    #[test]
+    fn test_reflect_index() {
+        let expected = [2, 3, 2, 1, 0, 1, 2, 3, 2, 1, 0, 1, 2];
+        for (i, &e) in (-4..9).zip(expected.iter()) {
+            assert_eq!(reflect_index(i, 4), e, "index {}", i);
+        }
+        assert_eq!(reflect_index(-1, 4), 1);
+        assert_eq!(reflect_index(4, 4), 2);
+        assert_eq!(reflect_index(-100, 1), 0);
+        assert_eq!(reflect_index(5, 0), 0);
+        assert_eq!(reflect_index(-1, 2), 1);
+        assert_eq!(reflect_index(2, 2), 0);
+        assert!(reflect_index(isize::MIN, usize::MAX) < usize::MAX);
+        assert_eq!(
+            reflect_index(isize::MAX, 3),
+            reflect_index(isize::MAX % 4, 3)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_diff() {
+        assert_eq!([1, 4, 9, 16].diff(), [3, 5, 7]);
+        assert_eq!([10i32, 5, -5].diff(), [-5, -10]);
+        assert_eq!([i8::MIN, i8::MAX].diff(), [-1]);
+        assert_eq!([0.5f64, 0.25, 1.0].diff(), [-0.25, 0.75]);
+        assert!(([] as [u32; 0]).diff().is_empty());
+        assert!([7u64].diff().is_empty());
+        let a = [3u8, 200, 17, 0, 255];
+        let mut acc = a[0];
+        let rebuilt: Vec<u8> = core::iter::once(a[0])
+            .chain(a.diff().into_iter().map(|d| {
+                acc = acc.wrapping_add(d);
+                acc
+            }))
+            .collect();
+        assert_eq!(rebuilt, a);
+    }
+
+    #[test]
+    fn test_trapezoidal_integral() {
+        assert_eq!([2.0f64, 2.0, 2.0].trapezoidal_integral(1.0), 4.0);
+        assert_eq!([2.0f32, 2.0, 2.0].trapezoidal_integral(0.25), 1.0);
+        assert_eq!([1.0f64, 3.0].trapezoidal_integral(2.0), 4.0);
+        assert_eq!([-1.0f64, 1.0].trapezoidal_integral(1.0), 0.0);
+        assert_eq!([5.0f64].trapezoidal_integral(1.0), 0.0);
+        assert_eq!(([] as [f32; 0]).trapezoidal_integral(1.0), 0.0);
+        let n = 1001;
+        let dx = 1.0 / (n - 1) as f64;
+        let ramp: Vec<f64> = (0..n).map(|i| i as f64 * dx).collect();
+        assert!((ramp.trapezoidal_integral(dx) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_downsample_majority() {
+        let labels = [0u8, 0, 1, 1, 2, 2, 2, 0];
+        assert_eq!(labels.downsample_majority(2), [0, 1, 2, 2]);
+        assert_eq!(labels.downsample_majority(4), [0, 2]);
+        assert_eq!(labels.downsample_majority(3), [0, 2, 2]);
+        assert_eq!(labels.downsample_majority(1), labels);
+        assert_eq!(labels.downsample_majority(100), [0]);
+        assert!(labels.downsample_majority(0).is_empty());
+        assert!(([] as [u8; 0]).downsample_majority(2).is_empty());
+        let names = ["sky", "tree", "tree", "sky", "road"];
+        assert_eq!(names.downsample_majority(2), ["sky", "tree", "road"]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_fields() {
+        assert_eq!(encode_fields(&[(0b101, 3), (0b11, 2)]), [0b1011_1000]);
+        assert_eq!(encode_fields(&[(0b1, 1), (0xFF, 8)]), [0xFF, 0x80]);
+        assert_eq!(encode_fields(&[(0x3FF, 10), (0, 6)]), [0xFF, 0xC0]);
+        assert_eq!(encode_fields(&[(0xFFFF, 4)]), [0xF0]);
+        assert_eq!(encode_fields(&[(u64::MAX, 64)]), [0xFF; 8]);
+        assert_eq!(encode_fields(&[(1, 72)]), [0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(encode_fields(&[(7, 0), (1, 1)]), [0x80]);
+        assert!(encode_fields(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_weighted_index() {
+        let w = [1u32, 1, 2];
+        assert_eq!(w.weighted_index(0.75), Some(2));
+        assert_eq!(w.weighted_index(0.0), Some(0));
+        assert_eq!(w.weighted_index(0.2499), Some(0));
+        assert_eq!(w.weighted_index(0.25), Some(1));
+        assert_eq!(w.weighted_index(0.5), Some(2));
+        assert_eq!(w.weighted_index(0.9999), Some(2));
+        assert_eq!(w.weighted_index(1.0), Some(2));
+        assert_eq!(w.weighted_index(-1.0), Some(0));
+        assert_eq!([0, 3, 0].weighted_index(0.0), Some(1));
+        assert_eq!([0, 3, 0].weighted_index(1.0), Some(1));
+        assert_eq!([-5i32, 1].weighted_index(0.0), Some(1));
+        assert_eq!(([] as [f64; 0]).weighted_index(0.5), None);
+        assert_eq!([0u8, 0].weighted_index(0.5), None);
+        assert_eq!([-1.0f64, f64::NAN].weighted_index(0.5), None);
+        let mut hits = [0usize; 3];
+        for i in 0..1000 {
+            hits[[1.0f64, 2.0, 7.0]
+                .weighted_index(i as f64 / 1000.0)
+                .unwrap()] += 1;
+        }
+        assert_eq!(hits, [100, 200, 700]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_merge_ranges() {
+        assert_eq!([(1, 3), (2, 5), (7, 8)].merge_ranges(), [(1, 5), (7, 8)]);
+        assert_eq!([(7, 8), (2, 5), (1, 3)].merge_ranges(), [(1, 5), (7, 8)]);
+        assert_eq!([(1u8, 10), (2, 3), (4, 9)].merge_ranges(), [(1, 10)]);
+        assert_eq!([(1u32, 3), (4, 6)].merge_ranges(), [(1, 6)]);
+        assert_eq!([(1u32, 3), (5, 6)].merge_ranges(), [(1, 3), (5, 6)]);
+        assert_eq!([(5i32, -5), (-10, -6)].merge_ranges(), [(-10, 5)]);
+        assert_eq!(
+            [(250u8, 255), (0, 0), (255, 255)].merge_ranges(),
+            [(0, 0), (250, 255)]
+        );
+        assert!(([] as [(u16, u16); 0]).merge_ranges().is_empty());
+    }
+
+    #[test]
+    fn test_autocorrelation() {
+        let period = 8;
+        let signal: Vec<f64> = (0..64)
+            .map(|i| (2.0 * std::f64::consts::PI * i as f64 / period as f64).sin())
+            .collect();
+        let peak = (1..32)
+            .max_by(|&a, &b| {
+                signal
+                    .autocorrelation(a)
+                    .partial_cmp(&signal.autocorrelation(b))
+                    .unwrap()
+            })
+            .unwrap();
+        assert_eq!(peak, period);
+        assert!(signal.autocorrelation(period / 2) < 0.0);
+        assert_eq!(signal.autocorrelation(64), 0.0);
+        assert_eq!(([] as [f32; 0]).autocorrelation(0), 0.0);
+        assert_eq!([3.0f32].autocorrelation(0), 9.0);
+    }
+
+    #[test]
+    fn test_transpose_into() {
+        let m = [1, 2, 3, 4, 5, 6];
+        let mut t = [0; 6];
+        m.transpose_into(2, 3, &mut t);
+        assert_eq!(t, [1, 4, 2, 5, 3, 6]);
+        let mut back = [0; 6];
+        t.transpose_into(3, 2, &mut back);
+        assert_eq!(back, m);
+        let mut big = [0u8; 8];
+        [7u8, 8].transpose_into(1, 2, &mut big);
+        assert_eq!(big, [7, 8, 0, 0, 0, 0, 0, 0]);
+        ([] as [u8; 0]).transpose_into(0, 5, &mut []);
+    }
+
+    #[test]
+    #[should_panic(expected = "source length mismatch")]
+    fn test_transpose_into_bad_shape() {
+        [1, 2, 3].transpose_into(2, 2, &mut [0; 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "destination too small")]
+    fn test_transpose_into_small_dst() {
+        [1, 2, 3, 4].transpose_into(2, 2, &mut [0; 3]);
+    }
+
+    #[test]
+    fn test_centroid() {
+        assert_eq!([1u32, 4, 6, 4, 1].centroid(), Some(2.0));
+        assert_eq!([0.5f64, 1.0, 2.0, 1.0, 0.5].centroid(), Some(2.0));
+        assert_eq!([0i32, 0, 5].centroid(), Some(2.0));
+        assert_eq!([1.0f32, 3.0].centroid(), Some(0.75));
+        let empty: [f32; 0] = [];
+        assert_eq!(empty.centroid(), None);
+        assert_eq!([0.0f64; 4].centroid(), None);
+        assert_eq!([1i8, -1].centroid(), None);
+    }
 }
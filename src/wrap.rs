@@ -1,6 +1,9 @@
 //! 类型包装类契定。
 //!
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// 定义内部类型拷贝契定。
 pub trait InnerCopy<T> {
     /// 返回内部类型拷贝。
@@ -16,8 +19,57 @@ pub trait InnerRefer<T> {
     fn inner_mut(&mut self) -> &mut T;
 }
 
+/// 定义消耗包装并取出内部类型的契定。
+pub trait IntoInner<T> {
+    /// 消耗当前包装，返回内部类型。
+    fn into_inner(self) -> T;
+}
+
+/// 定义内部类型大小契定。
+pub trait InnerSize {
+    /// 返回内部类型所占的字节数。
+    fn inner_size(&self) -> usize;
+}
+
+/// 定义将包装类型数组转化至内部类型数组指针的契定。
+pub trait InnerSlicePtr<T> {
+    /// 返回将数组首地址重新解释为内部类型的常量指针。
+    fn as_inner_ptr(&self) -> *const T;
+}
+
+/// 定义包装类型与裸指针之间转移所有权的契定。
+pub trait IntoFromRaw<T>: Sized {
+    /// 消耗当前包装并将内部类型转移至堆上，返回其裸指针。
+    ///
+    /// 返回的指针需通过 [from_raw] 收回，否则内部类型将被泄漏。
+    ///
+    /// [from_raw]: #tymethod.from_raw
+    fn into_raw(self) -> *mut T;
+
+    /// 从 [into_raw] 返回的裸指针收回包装。
+    ///
+    /// [into_raw]: #tymethod.into_raw
+    ///
+    /// # Safety
+    ///
+    /// `ptr` 必须来自同类型的 [into_raw] 且只能收回一次。
+    unsafe fn from_raw(ptr: *mut T) -> Self;
+}
+
+/// 定义枚举集合与比特标志掩码之间互相转换的契定。
+#[cfg(feature = "alloc")]
+pub trait EnumBitflags<M>: Sized {
+    /// 将枚举集合转换为掩码，每个枚举值对应 `1 << 判别值` 的比特位。
+    fn to_mask(flags: &[Self]) -> M;
+
+    /// 将掩码中已置位的比特位解码为枚举集合。
+    fn from_mask(mask: M) -> Vec<Self>;
+}
+
 /// 实现包装枚举两者之间的 [From] 特性。
 ///
+/// 两个枚举的大小及对齐方式必须一致，否则会在编译期报错。
+///
 /// [From]: https://doc.rust-lang.org/std/convert/trait.From.html
 ///
 /// # Examples
@@ -59,15 +111,21 @@ pub trait InnerRefer<T> {
 #[macro_export]
 macro_rules! impl_from_between_enum {
     ($Wrapper:ty, $Inner:ty) => {
+        const _: () = assert!(
+            ::core::mem::size_of::<$Wrapper>() == ::core::mem::size_of::<$Inner>()
+                && ::core::mem::align_of::<$Wrapper>() == ::core::mem::align_of::<$Inner>(),
+            "wrapper and inner enums must have the same size and alignment"
+        );
+
         impl From<$Inner> for $Wrapper {
             fn from(val: $Inner) -> Self {
-                unsafe { std::mem::transmute::<$Inner, Self>(val) }
+                unsafe { ::core::mem::transmute::<$Inner, Self>(val) }
             }
         }
 
         impl From<$Wrapper> for $Inner {
             fn from(val: $Wrapper) -> Self {
-                unsafe { std::mem::transmute::<$Wrapper, Self>(val) }
+                unsafe { ::core::mem::transmute::<$Wrapper, Self>(val) }
             }
         }
     };
@@ -75,6 +133,8 @@ macro_rules! impl_from_between_enum {
 
 /// 实现包装枚举的 [From] 及 [Into] 特性。
 ///
+/// 两个枚举的大小及对齐方式必须一致，否则会在编译期报错。
+///
 /// [From]: https://doc.rust-lang.org/std/convert/trait.From.html
 /// [Into]: https://doc.rust-lang.org/std/convert/trait.Into.html
 ///
@@ -117,15 +177,143 @@ macro_rules! impl_from_between_enum {
 #[macro_export]
 macro_rules! impl_from_into_for_enum {
     ($Wrapper:ty, $Inner:ty) => {
+        const _: () = assert!(
+            ::core::mem::size_of::<$Wrapper>() == ::core::mem::size_of::<$Inner>()
+                && ::core::mem::align_of::<$Wrapper>() == ::core::mem::align_of::<$Inner>(),
+            "wrapper and inner enums must have the same size and alignment"
+        );
+
         impl From<$Inner> for $Wrapper {
             fn from(val: $Inner) -> Self {
-                unsafe { std::mem::transmute::<$Inner, Self>(val) }
+                unsafe { ::core::mem::transmute::<$Inner, Self>(val) }
             }
         }
 
         impl Into<$Inner> for $Wrapper {
             fn into(self) -> $Inner {
-                unsafe { std::mem::transmute::<Self, $Inner>(self) }
+                unsafe { ::core::mem::transmute::<Self, $Inner>(self) }
+            }
+        }
+    };
+}
+
+/// 实现包装枚举从内部类型转换的 [TryFrom] 特性，拒绝不对应任何变体的判别值。
+///
+/// 与 [impl_from_into_for_enum] 不同，本宏逐一比较判别值而不进行 `transmute`，
+/// 适用于处理不可信的 FFI 返回值。由于宏无法枚举变体，需要显式列出有效变体；
+/// 内部类型须为整数或无字段枚举，转换失败时原样返回该值。
+///
+/// [TryFrom]: https://doc.rust-lang.org/std/convert/trait.TryFrom.html
+/// [impl_from_into_for_enum]: macro.impl_from_into_for_enum.html
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{impl_try_from_for_enum};
+/// use std::convert::TryFrom;
+///
+/// mod ffi {
+///     // The enum in ffi with C style.
+///     #[allow(non_camel_case_types)]
+///     pub type MODE_E = u32;
+///     pub const MODE_E_A: MODE_E = 0;
+///     pub const MODE_E_B: MODE_E = 1;
+///     pub const MODE_E_C: MODE_E = 2;
+/// }
+///
+/// // The enum wrapped with Rust style.
+/// #[repr(u32)]
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd)]
+/// pub enum Mode {
+///     A = ffi::MODE_E_A,
+///     B = ffi::MODE_E_B,
+///     C = ffi::MODE_E_C,
+/// }
+///
+/// impl_try_from_for_enum!(Mode, ffi::MODE_E, [A, B, C]);
+///
+/// assert_eq!(Mode::try_from(ffi::MODE_E_B), Ok(Mode::B));
+/// assert_eq!(Mode::try_from(99), Err(99));
+/// ```
+#[macro_export]
+macro_rules! impl_try_from_for_enum {
+    ($Wrapper:ty, $Inner:ty, [$($Variant:ident),* $(,)?]) => {
+        impl ::core::convert::TryFrom<$Inner> for $Wrapper {
+            type Error = $Inner;
+
+            fn try_from(val: $Inner) -> Result<Self, Self::Error> {
+                $(
+                    if val as i128 == Self::$Variant as i128 {
+                        return Ok(Self::$Variant);
+                    }
+                )*
+                Err(val)
+            }
+        }
+    };
+}
+
+/// 实现枚举的 [EnumBitflags] 契定。
+///
+/// 由于宏无法枚举变体，需要显式列出参与转换的变体，枚举类型须实现 `Copy`。
+///
+/// 所列变体的判别值必须处于 `[0, 掩码位宽)` 之内，否则会在编译期报错；
+/// 未列出且判别值超出该范围的变体在 `to_mask` 中会被忽略。
+///
+/// [EnumBitflags]: trait.EnumBitflags.html
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{impl_enum_bitflags, EnumBitflags};
+///
+/// #[repr(u32)]
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// pub enum Flag {
+///     A,
+///     B,
+///     C,
+/// }
+///
+/// impl_enum_bitflags!(Flag, u32, [A, B, C]);
+///
+/// assert_eq!(Flag::to_mask(&[Flag::A, Flag::C]), 0b101);
+/// assert_eq!(Flag::from_mask(0b101), [Flag::A, Flag::C]);
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! impl_enum_bitflags {
+    ($Type:ty, $Mask:ty, [$($Variant:ident),* $(,)?]) => {
+        const _: () = {
+            $(
+                assert!(
+                    (<$Type>::$Variant as i128) >= 0
+                        && (<$Type>::$Variant as i128) < <$Mask>::BITS as i128,
+                    "enum discriminants must fit in the bit width of the mask"
+                );
+            )*
+        };
+
+        impl EnumBitflags<$Mask> for $Type {
+            fn to_mask(flags: &[Self]) -> $Mask {
+                flags.iter().fold(0, |mask, &flag| {
+                    let bit = flag as i128;
+                    if bit >= 0 && bit < <$Mask>::BITS as i128 {
+                        mask | ((1 as $Mask) << bit)
+                    } else {
+                        mask
+                    }
+                })
+            }
+
+            fn from_mask(mask: $Mask) -> $crate::__private::Vec<Self> {
+                let mut flags = $crate::__private::Vec::new();
+                $(
+                    if mask & ((1 as $Mask) << (Self::$Variant as $Mask)) != 0 {
+                        flags.push(Self::$Variant);
+                    }
+                )*
+                flags
             }
         }
     };
@@ -133,6 +321,8 @@ macro_rules! impl_from_into_for_enum {
 
 /// 实现包装结构两者之间的 [From] 契定。
 ///
+/// 默认内部字段名为 `inner`，可通过第三个参数指定其它字段，元组结构使用 `0`。
+///
 /// [From]: https://doc.rust-lang.org/std/convert/trait.From.html
 ///
 /// # Examples
@@ -180,15 +370,19 @@ macro_rules! impl_from_into_for_enum {
 #[macro_export]
 macro_rules! impl_from_between_struct {
     ($Wrapper:ty, $Inner:ty) => {
+        $crate::impl_from_between_struct!($Wrapper, $Inner, inner);
+    };
+
+    ($Wrapper:ty, $Inner:ty, $Field:tt) => {
         impl From<$Inner> for $Wrapper {
             fn from(val: $Inner) -> Self {
-                Self { inner: val }
+                Self { $Field: val }
             }
         }
 
         impl From<$Wrapper> for $Inner {
             fn from(val: $Wrapper) -> Self {
-                val.inner
+                val.$Field
             }
         }
     };
@@ -196,6 +390,8 @@ macro_rules! impl_from_between_struct {
 
 /// 实现包装结构的 [From] 及 [Into] 契定。
 ///
+/// 默认内部字段名为 `inner`，可通过第三个参数指定其它字段，元组结构使用 `0`。
+///
 /// [From]: https://doc.rust-lang.org/std/convert/trait.From.html
 /// [Into]: https://doc.rust-lang.org/std/convert/trait.Into.html
 ///
@@ -244,15 +440,19 @@ macro_rules! impl_from_between_struct {
 #[macro_export]
 macro_rules! impl_from_into_for_struct {
     ($Wrapper:ty, $Inner:ty) => {
+        $crate::impl_from_into_for_struct!($Wrapper, $Inner, inner);
+    };
+
+    ($Wrapper:ty, $Inner:ty, $Field:tt) => {
         impl From<$Inner> for $Wrapper {
             fn from(val: $Inner) -> Self {
-                Self { inner: val }
+                Self { $Field: val }
             }
         }
 
         impl Into<$Inner> for $Wrapper {
             fn into(self) -> $Inner {
-                self.inner
+                self.$Field
             }
         }
     };
@@ -260,6 +460,8 @@ macro_rules! impl_from_into_for_struct {
 
 /// 实现包装类型的 [InnerCopy] 契定。
 ///
+/// 默认内部字段名为 `inner`，可通过第三个参数指定其它字段，元组结构使用 `0`。
+///
 /// [InnerCopy]: trait.InnerCopy.html
 ///
 /// # Examples
@@ -279,9 +481,13 @@ macro_rules! impl_from_into_for_struct {
 #[macro_export]
 macro_rules! impl_inner_copy {
     ($Wrapper:ty, $Inner:ty) => {
+        $crate::impl_inner_copy!($Wrapper, $Inner, inner);
+    };
+
+    ($Wrapper:ty, $Inner:ty, $Field:tt) => {
         impl InnerCopy<$Inner> for $Wrapper {
             fn inner(&self) -> $Inner {
-                self.inner
+                self.$Field
             }
         }
     };
@@ -289,6 +495,8 @@ macro_rules! impl_inner_copy {
 
 /// 实现包装类型的 [InnerRefer] 契定。
 ///
+/// 默认内部字段名为 `inner`，可通过第三个参数指定其它字段，元组结构使用 `0`。
+///
 /// [InnerRefer]: trait.InnerRefer.html
 ///
 /// # Examples
@@ -307,13 +515,241 @@ macro_rules! impl_inner_copy {
 #[macro_export]
 macro_rules! impl_inner_refer {
     ($Wrapper:ty, $Inner:ty) => {
+        $crate::impl_inner_refer!($Wrapper, $Inner, inner);
+    };
+
+    ($Wrapper:ty, $Inner:ty, $Field:tt) => {
         impl InnerRefer<$Inner> for $Wrapper {
             fn inner(&self) -> &$Inner {
-                &self.inner
+                &self.$Field
             }
 
             fn inner_mut(&mut self) -> &mut $Inner {
-                &mut self.inner
+                &mut self.$Field
+            }
+        }
+    };
+}
+
+/// 实现包装类型的 [IntoInner] 契定。
+///
+/// 默认内部字段名为 `inner`，可通过第三个参数指定其它字段，元组结构使用 `0`。
+///
+/// [IntoInner]: trait.IntoInner.html
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{impl_into_inner, IntoInner};
+///
+/// struct Foo {
+///    inner: String,
+/// }
+///
+/// impl_into_inner!(Foo, String);
+///
+/// let f = Foo { inner: String::from("pavo") };
+/// assert_eq!(f.into_inner(), "pavo");
+/// ```
+#[macro_export]
+macro_rules! impl_into_inner {
+    ($Wrapper:ty, $Inner:ty) => {
+        $crate::impl_into_inner!($Wrapper, $Inner, inner);
+    };
+
+    ($Wrapper:ty, $Inner:ty, $Field:tt) => {
+        impl IntoInner<$Inner> for $Wrapper {
+            fn into_inner(self) -> $Inner {
+                self.$Field
+            }
+        }
+    };
+}
+
+/// 实现包装类型的 [InnerSize] 契定。
+///
+/// [InnerSize]: trait.InnerSize.html
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{impl_inner_size, InnerSize};
+///
+/// struct Bar {
+///     a: u32,
+///     b: u32,
+/// }
+///
+/// struct Foo {
+///    inner: Bar,
+/// }
+///
+/// impl_inner_size!(Foo, Bar);
+///
+/// let f = Foo { inner: Bar { a: 1, b: 2 } };
+/// assert_eq!(f.inner_size(), 8);
+/// ```
+#[macro_export]
+macro_rules! impl_inner_size {
+    ($Wrapper:ty, $Inner:ty) => {
+        impl InnerSize for $Wrapper {
+            fn inner_size(&self) -> usize {
+                ::core::mem::size_of::<$Inner>()
+            }
+        }
+    };
+}
+
+/// 实现包装类型的 [IntoFromRaw] 契定，需要启用 `alloc` 特性。
+///
+/// 默认内部字段名为 `inner`，可通过第三个参数指定其它字段，元组结构使用 `0`。
+///
+/// [IntoFromRaw]: trait.IntoFromRaw.html
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{impl_into_from_raw, IntoFromRaw};
+///
+/// struct Bar {
+///     v: usize,
+/// }
+///
+/// struct Foo {
+///    inner: Bar,
+/// }
+///
+/// impl_into_from_raw!(Foo, Bar);
+///
+/// let p = Foo { inner: Bar { v: 123 } }.into_raw();
+/// let f = unsafe { Foo::from_raw(p) };
+/// assert_eq!(f.inner.v, 123);
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! impl_into_from_raw {
+    ($Wrapper:ty, $Inner:ty) => {
+        $crate::impl_into_from_raw!($Wrapper, $Inner, inner);
+    };
+
+    ($Wrapper:ty, $Inner:ty, $Field:tt) => {
+        impl IntoFromRaw<$Inner> for $Wrapper {
+            fn into_raw(self) -> *mut $Inner {
+                $crate::__private::Box::into_raw($crate::__private::Box::new(self.$Field))
+            }
+
+            unsafe fn from_raw(ptr: *mut $Inner) -> Self {
+                Self {
+                    $Field: *$crate::__private::Box::from_raw(ptr),
+                }
+            }
+        }
+    };
+}
+
+/// 实现包装类型数组的 [InnerSlicePtr] 契定。
+///
+/// 包装类型必须为 `#[repr(transparent)]`，由调用者保证；宏只能在编译期断言两者大小
+/// 及对齐方式一致，无法检查 `#[repr(transparent)]` 本身。
+///
+/// [InnerSlicePtr]: trait.InnerSlicePtr.html
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{impl_inner_slice_ptr, InnerSlicePtr};
+///
+/// #[derive(Clone, Copy)]
+/// struct Bar {
+///     v: u32,
+/// }
+///
+/// #[repr(transparent)]
+/// struct Foo {
+///    inner: Bar,
+/// }
+///
+/// impl_inner_slice_ptr!(Foo, Bar);
+///
+/// let v = vec![Foo { inner: Bar { v: 1 } }, Foo { inner: Bar { v: 2 } }];
+/// let p = v.as_inner_ptr();
+/// assert_eq!(unsafe { (*p.add(1)).v }, 2);
+/// ```
+#[macro_export]
+macro_rules! impl_inner_slice_ptr {
+    ($Wrapper:ty, $Inner:ty) => {
+        const _: () = assert!(
+            ::core::mem::size_of::<$Wrapper>() == ::core::mem::size_of::<$Inner>()
+                && ::core::mem::align_of::<$Wrapper>() == ::core::mem::align_of::<$Inner>(),
+            "wrapper and inner types must have the same size and alignment"
+        );
+
+        impl InnerSlicePtr<$Inner> for [$Wrapper] {
+            fn as_inner_ptr(&self) -> *const $Inner {
+                self.as_ptr() as *const $Inner
+            }
+        }
+    };
+}
+
+/// 实现包装结构到内部类型的 [Deref] 及 [DerefMut] 契定，使包装可直接调用内部类型的方法。
+///
+/// 解引用强制转换并非总是期望的行为，因此未包含在 [impl_struct_wrapper] 中。
+///
+/// 默认内部字段名为 `inner`，可通过第三个参数指定其它字段，元组结构使用 `0`。
+///
+/// [Deref]: https://doc.rust-lang.org/std/ops/trait.Deref.html
+/// [DerefMut]: https://doc.rust-lang.org/std/ops/trait.DerefMut.html
+/// [impl_struct_wrapper]: macro.impl_struct_wrapper.html
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{impl_deref};
+///
+/// struct Bar {
+///     value: u32,
+/// }
+///
+/// impl Bar {
+///     fn value(&self) -> u32 {
+///         self.value
+///     }
+///
+///     fn set_value(&mut self, value: u32) {
+///         self.value = value;
+///     }
+/// }
+///
+/// struct Foo {
+///    inner: Bar,
+/// }
+///
+/// impl_deref!(Foo, Bar);
+///
+/// let mut f = Foo { inner: Bar { value: 1 } };
+/// assert_eq!(f.value(), 1);
+/// f.set_value(2);
+/// assert_eq!(f.inner.value, 2);
+/// ```
+#[macro_export]
+macro_rules! impl_deref {
+    ($Wrapper:ty, $Inner:ty) => {
+        $crate::impl_deref!($Wrapper, $Inner, inner);
+    };
+
+    ($Wrapper:ty, $Inner:ty, $Field:tt) => {
+        impl ::core::ops::Deref for $Wrapper {
+            type Target = $Inner;
+
+            fn deref(&self) -> &$Inner {
+                &self.$Field
+            }
+        }
+
+        impl ::core::ops::DerefMut for $Wrapper {
+            fn deref_mut(&mut self) -> &mut $Inner {
+                &mut self.$Field
             }
         }
     };
@@ -322,6 +758,8 @@ macro_rules! impl_inner_refer {
 /// 实现包装结构的通用契定。
 /// 包括：[AsRef], [AsPtr], [AsPtrMut], [InnerRefer]。
 ///
+/// 默认内部字段名为 `inner`，可通过第三个参数指定其它字段，元组结构使用 `0`。
+///
 /// [AsRef]: https://doc.rust-lang.org/std/convert/trait.AsRef.html
 /// [AsPtr]: trait.AsPtr.html
 /// [AsPtrMut]: trait.AsPtrMut.html
@@ -346,17 +784,63 @@ macro_rules! impl_inner_refer {
 /// assert_eq!(std::ptr::eq(f.as_ref(), &f.inner), true);
 /// assert_eq!(std::ptr::eq(f.as_ref(), f.inner()), true);
 /// assert_eq!(std::ptr::eq(f.inner(), &f.inner), true);
+///
+/// // Tuple struct wrappers.
+/// #[derive(Debug)]
+/// struct Handle(Bar);
+///
+/// impl_struct_wrapper!(Handle, Bar, 0);
+///
+/// let h = Handle::from(Bar {});
+/// assert_eq!(std::ptr::eq(h.inner(), &h.0), true);
 /// ```
 #[macro_export(local_inner_macros)]
 macro_rules! impl_struct_wrapper {
     ($Wrapper:ty, $Inner:ty) => {
+        impl_struct_wrapper!($Wrapper, $Inner, inner);
+    };
+
+    ($Wrapper:ty, $Inner:ty, $Field:tt) => {
         impl_as_mut_and_ref!($Wrapper);
-        impl_as_ref!($Wrapper, $Inner, inner);
-        impl_as_mut!($Wrapper, $Inner, inner);
+        impl_as_ref!($Wrapper, $Inner, $Field);
+        impl_as_mut!($Wrapper, $Inner, $Field);
         // impl_as_ptr!($Wrapper, $Inner);
         // impl_as_ptr_mut!($Wrapper, $Inner);
-        impl_from_between_struct!($Wrapper, $Inner);
-        impl_inner_refer!($Wrapper, $Inner);
+        impl_from_between_struct!($Wrapper, $Inner, $Field);
+        impl_inner_refer!($Wrapper, $Inner, $Field);
+    };
+}
+
+/// 在 [impl_struct_wrapper] 的基础上同时实现 [Deref] 及 [DerefMut] 契定。
+///
+/// [impl_struct_wrapper]: macro.impl_struct_wrapper.html
+/// [Deref]: https://doc.rust-lang.org/std/ops/trait.Deref.html
+/// [DerefMut]: https://doc.rust-lang.org/std/ops/trait.DerefMut.html
+///
+/// # Examples
+///
+/// ```
+/// use pavo_traits::{impl_struct_wrapper_deref, InnerRefer};
+///
+/// #[derive(Debug)]
+/// struct Handle(Vec<u8>);
+///
+/// impl_struct_wrapper_deref!(Handle, Vec<u8>, 0);
+///
+/// let mut h = Handle::from(vec![1, 2]);
+/// h.push(3);
+/// assert_eq!(h.len(), 3);
+/// assert_eq!(h.inner(), &[1, 2, 3]);
+/// ```
+#[macro_export(local_inner_macros)]
+macro_rules! impl_struct_wrapper_deref {
+    ($Wrapper:ty, $Inner:ty) => {
+        impl_struct_wrapper_deref!($Wrapper, $Inner, inner);
+    };
+
+    ($Wrapper:ty, $Inner:ty, $Field:tt) => {
+        impl_struct_wrapper!($Wrapper, $Inner, $Field);
+        impl_deref!($Wrapper, $Inner, $Field);
     };
 }
 
@@ -394,8 +878,209 @@ mod tests {
             inner: Arc::new(123),
         };
         assert_eq!(&**f.inner(), &123usize);
-        assert_eq!(std::ptr::eq(f.inner(), &f.inner), true);
+        assert!(std::ptr::eq(f.inner(), &f.inner));
         *Arc::make_mut(f.inner_mut()) = 456;
         assert_eq!(&**f.inner(), &456usize);
     }
+
+    #[derive(Debug)]
+    struct FooOwned {
+        inner: String,
+    }
+
+    impl_into_inner!(FooOwned, String);
+
+    #[test]
+    fn test_into_inner() {
+        let f = FooOwned {
+            inner: String::from("pavo"),
+        };
+        let p = f.inner.as_ptr();
+        let s = f.into_inner();
+        assert_eq!(s, "pavo");
+        assert_eq!(s.as_ptr(), p);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[derive(Debug)]
+    struct FooRaw {
+        inner: Arc<usize>,
+    }
+
+    #[cfg(feature = "alloc")]
+    impl_into_from_raw!(FooRaw, Arc<usize>);
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_into_from_raw() {
+        let v = Arc::new(123);
+        let p = FooRaw { inner: v.clone() }.into_raw();
+        assert_eq!(Arc::strong_count(&v), 2);
+        assert_eq!(unsafe { &**p }, &123usize);
+        let f = unsafe { FooRaw::from_raw(p) };
+        assert!(Arc::ptr_eq(&f.inner, &v));
+        drop(f);
+        assert_eq!(Arc::strong_count(&v), 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[repr(u8)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Flag {
+        A,
+        B,
+        C,
+        D = 7,
+        E = 8,
+    }
+
+    #[cfg(feature = "alloc")]
+    impl_enum_bitflags!(Flag, u8, [A, B, C, D]);
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_enum_bitflags() {
+        assert_eq!(Flag::to_mask(&[]), 0);
+        assert_eq!(Flag::to_mask(&[Flag::A, Flag::C]), 0b101);
+        assert_eq!(Flag::to_mask(&[Flag::C, Flag::C]), 0b100);
+        assert_eq!(Flag::to_mask(&[Flag::B, Flag::D]), 0b1000_0010);
+        assert_eq!(Flag::to_mask(&[Flag::A, Flag::E]), 0b1);
+        assert_eq!(Flag::from_mask(0b101), [Flag::A, Flag::C]);
+        assert_eq!(Flag::from_mask(0b0111_1000), []);
+        assert_eq!(
+            Flag::from_mask(u8::MAX),
+            [Flag::A, Flag::B, Flag::C, Flag::D]
+        );
+    }
+
+    #[repr(C)]
+    #[derive(Debug, Default)]
+    struct DataSize {
+        a: usize,
+        b: usize,
+        c: usize,
+        d: usize,
+    }
+
+    #[derive(Debug, Default)]
+    struct FooSize {
+        inner: DataSize,
+    }
+
+    impl_inner_size!(FooSize, DataSize);
+
+    #[test]
+    fn test_inner_size() {
+        let f = FooSize::default();
+        assert_eq!(f.inner_size(), 4 * std::mem::size_of::<usize>());
+        assert_eq!(f.inner_size(), std::mem::size_of_val(&f.inner));
+        assert_eq!(f.inner.a + f.inner.b + f.inner.c + f.inner.d, 0);
+    }
+
+    #[repr(transparent)]
+    #[derive(Debug)]
+    struct FooSlice {
+        inner: u64,
+    }
+
+    impl_inner_slice_ptr!(FooSlice, u64);
+
+    #[test]
+    fn test_inner_slice_ptr() {
+        let v: Vec<FooSlice> = (0..4).map(|i| FooSlice { inner: i * 10 }).collect();
+        let p = v.as_inner_ptr();
+        assert_eq!(p, v.as_ptr() as *const u64);
+        let inner = unsafe { std::slice::from_raw_parts(p, v.len()) };
+        assert_eq!(inner, [0, 10, 20, 30]);
+        assert_eq!(inner[3], v[3].inner);
+    }
+
+    mod ffi {
+        #[repr(i32)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        #[allow(non_camel_case_types, dead_code)]
+        pub enum LEVEL_E {
+            LEVEL_E_LOW = -1,
+            LEVEL_E_HIGH = 1,
+        }
+
+        #[allow(non_camel_case_types)]
+        pub type COLOR_E = u8;
+    }
+
+    #[repr(i32)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Level {
+        Low = -1,
+        High = 1,
+    }
+
+    #[repr(u8)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Color {
+        Red = 1,
+        Green = 2,
+        Blue = 4,
+    }
+
+    impl_try_from_for_enum!(Level, ffi::LEVEL_E, [Low, High]);
+    impl_try_from_for_enum!(Color, ffi::COLOR_E, [Red, Green, Blue]);
+
+    #[test]
+    fn test_try_from_for_enum() {
+        use std::convert::TryFrom;
+
+        assert_eq!(Level::try_from(ffi::LEVEL_E::LEVEL_E_LOW), Ok(Level::Low));
+        assert_eq!(Level::try_from(ffi::LEVEL_E::LEVEL_E_HIGH), Ok(Level::High));
+        assert_eq!(Color::try_from(2), Ok(Color::Green));
+        assert_eq!(Color::try_from(4), Ok(Color::Blue));
+        assert_eq!(Color::try_from(0), Err(0));
+        assert_eq!(Color::try_from(3), Err(3));
+        assert_eq!(Color::try_from(255), Err(255));
+    }
+
+    #[derive(Debug)]
+    struct TupleCopy(u32);
+
+    impl_inner_copy!(TupleCopy, u32, 0);
+    impl_from_between_struct!(TupleCopy, u32, 0);
+
+    #[derive(Debug)]
+    struct TupleWrapper(Arc<usize>);
+
+    impl_struct_wrapper!(TupleWrapper, Arc<usize>, 0);
+
+    #[test]
+    fn test_tuple_struct_wrapper() {
+        let c = TupleCopy::from(7);
+        assert_eq!(c.inner(), 7);
+        let v: u32 = c.into();
+        assert_eq!(v, 7);
+
+        let mut w = TupleWrapper::from(Arc::new(123));
+        assert!(std::ptr::eq(w.inner(), &w.0));
+        assert!(std::ptr::eq(w.as_ref(), &w.0));
+        *Arc::make_mut(w.inner_mut()) = 456;
+        assert_eq!(*w.0, 456);
+        let inner: Arc<usize> = w.into();
+        assert_eq!(*inner, 456);
+    }
+
+    #[derive(Debug)]
+    struct DerefWrapper {
+        inner: Vec<u32>,
+    }
+
+    impl_struct_wrapper_deref!(DerefWrapper, Vec<u32>);
+
+    #[test]
+    fn test_struct_wrapper_deref() {
+        let mut w = DerefWrapper::from(vec![1, 2]);
+        w.push(3);
+        assert_eq!(w.len(), 3);
+        assert_eq!(&*w, &[1, 2, 3]);
+        assert!(std::ptr::eq(&*w, w.inner()));
+        w[0] = 7;
+        assert_eq!(w.inner, [7, 2, 3]);
+    }
 }